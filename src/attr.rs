@@ -0,0 +1,893 @@
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    meta::ParseNestedMeta, parenthesized, parse::ParseStream, punctuated::Punctuated,
+    spanned::Spanned, Attribute, Error, Expr, Field, Ident, LitStr, Path, Result, Token, Type,
+    Visibility, WherePredicate,
+};
+
+use crate::{
+    combine_errors,
+    diagnostic::{Message, EXPLAIN_OPTIONS},
+};
+
+// options accepted by `#[splat(...)]` on the struct
+const CONTAINER_OPTIONS: &[&str] = &[
+    "allow_single_field",
+    "rename",
+    "alias",
+    "vis",
+    "const_fn",
+    "doc_hidden",
+    "doc",
+    "into",
+    "try_into",
+    "by_ref",
+    "copy",
+    "bound",
+    "cfg",
+    "input",
+    "extra",
+    "constructor",
+    "wrap",
+    "deep",
+    "grouped",
+    "validate",
+    "error",
+    "clamp",
+    "range",
+    "msrv",
+    "debug_expand",
+    "impl_trait",
+    "doc_alias",
+    "crate",
+    "const_value",
+    "impl_default",
+    "impl_from",
+];
+
+// methods that can be requested with `extra(...)` on the struct
+const EXTRA_METHODS: &[&str] = &[
+    "fill",
+    "splat_with",
+    "splat_with_index",
+    "splat_with_name",
+    "try_splat_with",
+    "splat_default",
+    "update_all",
+    "set_all",
+    "replace_all",
+    "with_all",
+    "splat_boxed",
+    "splat_uninit",
+    "splat_in",
+    "splat_many",
+    "splat_seq",
+    "splat_gradient",
+    "splat_cycle",
+    "copy_from_slice",
+    "try_splat_parse",
+    "is_splat",
+    "splat_value",
+    "eq_splat",
+    "scale_all",
+    "add_all",
+    "to_ref_array",
+    "to_mut_array",
+    "splat_then",
+];
+
+// options accepted by `#[splat(...)]` on fields
+const FIELD_OPTIONS: &[&str] = &[
+    "skip",
+    "skip_with",
+    "with",
+    "with_ref",
+    "clone_with",
+    "nested",
+    "wrap",
+    "cast",
+    "checked_cast",
+    "into",
+    "share",
+];
+
+// field options that let a field of another type take part in the splat, with what each
+// one does, suggested when a field's type doesn't match
+pub(crate) const MISMATCH_WORKAROUNDS: &[(&str, &str)] = &[
+    (
+        "skip",
+        "leave it out of the splat and fill it with `Default::default()`",
+    ),
+    (
+        "skip_with = \"...\"",
+        "leave it out of the splat and fill it with the given expression",
+    ),
+    (
+        "with = \"...\"",
+        "fill it with the result of passing the value to the given function",
+    ),
+    ("nested", "fill it by passing the value to its own `splat`"),
+    (
+        "cast",
+        "fill it by converting the value to its type with `as`",
+    ),
+    (
+        "checked_cast",
+        "fill it by converting the value to its type with `TryFrom`, panicking if it doesn't fit",
+    ),
+    (
+        "into",
+        "fill it by converting the value to its type with `From`",
+    ),
+];
+
+// pairs of options on the struct that contradict each other
+const CONTAINER_CONFLICTS: &[(&str, &str)] = &[
+    ("doc_hidden", "doc"),
+    ("const_fn", "into"),
+    ("by_ref", "into"),
+    ("input", "into"),
+    ("input", "by_ref"),
+    ("input", "const_fn"),
+    ("deep", "wrap"),
+    ("grouped", "into"),
+    ("grouped", "try_into"),
+    ("grouped", "by_ref"),
+    ("grouped", "input"),
+    ("grouped", "wrap"),
+    ("grouped", "deep"),
+    ("grouped", "alias"),
+    ("grouped", "extra"),
+    ("grouped", "constructor"),
+    ("grouped", "validate"),
+    ("try_into", "validate"),
+    ("clamp", "range"),
+    ("clamp", "by_ref"),
+    ("clamp", "const_fn"),
+    ("clamp", "grouped"),
+    ("range", "by_ref"),
+    ("range", "const_fn"),
+    ("range", "grouped"),
+    ("range", "try_into"),
+    ("range", "validate"),
+    ("impl_trait", "const_fn"),
+    ("impl_trait", "vis"),
+    ("impl_trait", "grouped"),
+    ("impl_trait", "doc_alias"),
+    ("grouped", "const_value"),
+    ("grouped", "impl_default"),
+    ("grouped", "impl_from"),
+    ("range", "impl_from"),
+];
+
+// pairs of options on the same field that contradict each other
+const FIELD_CONFLICTS: &[(&str, &str)] = &[
+    ("skip", "skip_with"),
+    ("skip", "with"),
+    ("skip", "with_ref"),
+    ("skip_with", "with"),
+    ("skip_with", "with_ref"),
+    ("with", "with_ref"),
+    ("skip", "clone_with"),
+    ("skip_with", "clone_with"),
+    ("with_ref", "clone_with"),
+    ("skip", "nested"),
+    ("skip_with", "nested"),
+    ("with", "nested"),
+    ("with_ref", "nested"),
+    ("skip", "wrap"),
+    ("skip_with", "wrap"),
+    ("with", "wrap"),
+    ("with_ref", "wrap"),
+    ("nested", "wrap"),
+    ("skip", "cast"),
+    ("skip", "checked_cast"),
+    ("skip_with", "cast"),
+    ("skip_with", "checked_cast"),
+    ("with", "cast"),
+    ("with", "checked_cast"),
+    ("with_ref", "cast"),
+    ("with_ref", "checked_cast"),
+    ("nested", "cast"),
+    ("nested", "checked_cast"),
+    ("wrap", "cast"),
+    ("wrap", "checked_cast"),
+    ("cast", "checked_cast"),
+    ("skip", "into"),
+    ("skip_with", "into"),
+    ("with", "into"),
+    ("with_ref", "into"),
+    ("nested", "into"),
+    ("wrap", "into"),
+    ("cast", "into"),
+    ("checked_cast", "into"),
+    ("skip", "share"),
+    ("skip_with", "share"),
+    ("with", "share"),
+    ("with_ref", "share"),
+    ("nested", "share"),
+    ("wrap", "share"),
+    ("cast", "share"),
+    ("checked_cast", "share"),
+    ("into", "share"),
+    ("clone_with", "share"),
+];
+
+// pairs of an option on the struct and an option on a field that contradict each other,
+// struct option first, since the struct's options apply to every field
+const CONTAINER_FIELD_CONFLICTS: &[(&str, &str)] = &[
+    ("grouped", "with"),
+    ("grouped", "with_ref"),
+    ("grouped", "clone_with"),
+    ("grouped", "nested"),
+    ("grouped", "wrap"),
+    ("grouped", "cast"),
+    ("grouped", "checked_cast"),
+    ("grouped", "into"),
+    ("grouped", "share"),
+    ("copy", "clone_with"),
+    ("const_fn", "clone_with"),
+    ("const_fn", "checked_cast"),
+    ("const_fn", "into"),
+    ("wrap", "share"),
+    ("deep", "share"),
+];
+
+// where a `#[splat(...)]` attribute was written
+#[derive(Clone, Copy)]
+enum Location {
+    Container,
+    Field,
+}
+
+impl Location {
+    fn options(self) -> &'static [&'static str] {
+        match self {
+            Location::Container => CONTAINER_OPTIONS,
+            Location::Field => FIELD_OPTIONS,
+        }
+    }
+
+    // gets the pairs of options that contradict each other when both are written here
+    fn conflicts(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Location::Container => CONTAINER_CONFLICTS,
+            Location::Field => FIELD_CONFLICTS,
+        }
+    }
+
+    fn other(self) -> Location {
+        match self {
+            Location::Container => Location::Field,
+            Location::Field => Location::Container,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Location::Container => "structs",
+            Location::Field => "fields",
+        }
+    }
+}
+
+// parses the `#[splat(...)]` attributes on the struct and each of its fields, reporting the
+// errors from all of them together
+pub(crate) fn parse_attrs(
+    attrs: &[Attribute],
+    fields: &Punctuated<Field, Token![,]>,
+) -> Result<(ContainerAttrs, Vec<FieldAttrs>)> {
+    let mut errors = Vec::new();
+    let container_attrs = ContainerAttrs::parse(attrs).unwrap_or_else(|error| {
+        errors.push(error);
+        ContainerAttrs::default()
+    });
+    let field_attrs = fields
+        .iter()
+        .map(|field| {
+            FieldAttrs::parse(&field.attrs).unwrap_or_else(|error| {
+                errors.push(error);
+                FieldAttrs::default()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // options on the struct apply to every field, so they are checked against each field's
+    errors.extend(find_conflicts(
+        Location::Container,
+        &container_attrs.options,
+        &[],
+    ));
+    for field_attrs in &field_attrs {
+        errors.extend(find_conflicts(
+            Location::Field,
+            &field_attrs.options,
+            &container_attrs.options,
+        ));
+    }
+
+    combine_errors(errors)?;
+    Ok((container_attrs, field_attrs))
+}
+
+// options set by `#[splat(...)]` attributes on the struct
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    // `allow_single_field` silences the warning for structs with a single field
+    pub(crate) allow_single_field: bool,
+    // `rename = "..."` names the generated method something other than `splat`
+    pub(crate) rename: Option<Ident>,
+    // `alias = "..."` generates more methods under the given names, delegating to the first
+    pub(crate) alias: Vec<Ident>,
+    // `vis = "..."` sets the visibility of the generated method
+    pub(crate) vis: Option<Visibility>,
+    // `const_fn` generates a `const fn` that copies the value instead of cloning it
+    pub(crate) const_fn: bool,
+    // `doc_hidden` hides the generated method from rustdoc
+    pub(crate) doc_hidden: bool,
+    // `doc = "..."` replaces the generated method's documentation
+    pub(crate) doc: Option<LitStr>,
+    // `into` takes any value that converts into the shared type
+    pub(crate) into: bool,
+    // `try_into` also generates a fallible `try_splat` taking any `TryInto` value
+    pub(crate) try_into: bool,
+    // `by_ref` takes a reference to the value and clones from it
+    pub(crate) by_ref: bool,
+    // `copy` requires the shared type to be Copy and copies it instead of cloning it
+    pub(crate) copy: bool,
+    // `bound = "..."` replaces the automatic bound on the generated methods
+    pub(crate) bound: Option<Punctuated<WherePredicate, Token![,]>>,
+    // `cfg(...)` gates everything generated behind the given predicate
+    pub(crate) cfg: Option<TokenStream2>,
+    // `input = "..."` takes a value of the given type and converts it into the shared type
+    pub(crate) input: Option<Type>,
+    // `extra(...)` lists the extra methods to generate alongside the splat
+    pub(crate) extra: Vec<Ident>,
+    // `constructor = "..."` builds the struct through the given associated function
+    pub(crate) constructor: Option<Ident>,
+    // `wrap = "..."` passes each field's value to the given functions, like `Arc::new`,
+    // outermost first, or turns off detecting common wrappers when empty
+    pub(crate) wrap: Option<Vec<Path>>,
+    // `deep = "..."` takes the given type and builds each field with its own type's `splat`
+    pub(crate) deep: Option<Type>,
+    // `grouped` takes a parameter for each distinct field type
+    pub(crate) grouped: bool,
+    // `validate = "..."` also generates `try_splat`, checking the struct with the given
+    // function, which returns the error type given by `error = "..."`
+    pub(crate) validate: Option<Path>,
+    pub(crate) error: Option<Type>,
+    // `clamp = "..."` clamps the value into the given range
+    pub(crate) clamp: Option<ValueRange>,
+    // `range = "..."` rejects values outside of the given range
+    pub(crate) range: Option<ValueRange>,
+    // `msrv = "..."` restricts the generated code to the given Rust version
+    pub(crate) msrv: Option<Msrv>,
+    // `debug_expand` reports the generated code as a warning
+    pub(crate) debug_expand: bool,
+    // `impl_trait = "..."` generates the method in an impl of the given trait
+    pub(crate) impl_trait: Option<Path>,
+    // `doc_alias = "..."` adds rustdoc search aliases to the generated method
+    pub(crate) doc_alias: Vec<String>,
+    // `crate = "..."` is the path of a crate re-exporting the crates the generated code uses
+    // outside of `core` and `std`, like `bumpalo`
+    pub(crate) crate_path: Option<Path>,
+    // `const_value(NAME = "...")` generates an associated constant with every field set to the
+    // given expression, for each name
+    pub(crate) const_value: Vec<ConstValue>,
+    // `impl_default` implements `Default` by splatting the value's own default, or the given
+    // expression with `impl_default = "..."`
+    pub(crate) impl_default: Option<Option<Expr>>,
+    // `impl_from` implements `From` for the shared type by splatting it
+    pub(crate) impl_from: bool,
+    options: Vec<(String, Span)>,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut container_attrs = Self::default();
+        let options = parse_splat_attrs(attrs, |meta| {
+            match option_name(meta).as_str() {
+                "allow_single_field" => container_attrs.allow_single_field = parse_flag(meta)?,
+                "rename" => container_attrs.rename = Some(parse_ident(meta)?),
+                "alias" => {
+                    let aliases = meta.value()?.parse::<LitStr>()?;
+                    container_attrs.alias = aliases
+                        .parse_with(Punctuated::<Ident, Token![,]>::parse_terminated)?
+                        .into_iter()
+                        .collect();
+                }
+                "vis" => container_attrs.vis = Some(meta.value()?.parse::<LitStr>()?.parse()?),
+                "const_fn" => container_attrs.const_fn = parse_flag(meta)?,
+                "doc_hidden" => container_attrs.doc_hidden = parse_flag(meta)?,
+                "doc" => container_attrs.doc = Some(meta.value()?.parse()?),
+                "into" => container_attrs.into = parse_flag(meta)?,
+                "try_into" => container_attrs.try_into = parse_flag(meta)?,
+                "by_ref" => container_attrs.by_ref = parse_flag(meta)?,
+                "copy" => container_attrs.copy = parse_flag(meta)?,
+                "input" => container_attrs.input = Some(meta.value()?.parse::<LitStr>()?.parse()?),
+                "constructor" => container_attrs.constructor = Some(parse_ident(meta)?),
+                "wrap" => container_attrs.wrap = Some(parse_wrap(meta)?),
+                "deep" => container_attrs.deep = Some(meta.value()?.parse::<LitStr>()?.parse()?),
+                "grouped" => container_attrs.grouped = parse_flag(meta)?,
+                "validate" => {
+                    container_attrs.validate = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                }
+                "error" => container_attrs.error = Some(meta.value()?.parse::<LitStr>()?.parse()?),
+                "clamp" => container_attrs.clamp = Some(parse_range(meta)?),
+                "range" => container_attrs.range = Some(parse_range(meta)?),
+                "msrv" => container_attrs.msrv = Some(parse_msrv(meta)?),
+                "debug_expand" => container_attrs.debug_expand = parse_flag(meta)?,
+                "impl_trait" => {
+                    container_attrs.impl_trait = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                }
+                "doc_alias" => {
+                    let doc_alias = meta.value()?.parse::<LitStr>()?;
+                    container_attrs.doc_alias = doc_alias
+                        .value()
+                        .split(',')
+                        .map(|alias| alias.trim().to_owned())
+                        .filter(|alias| !alias.is_empty())
+                        .collect();
+                }
+                "crate" => {
+                    container_attrs.crate_path = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                }
+                "extra" => container_attrs.extra = parse_extra(meta)?,
+                "const_value" => container_attrs.const_value = parse_const_value(meta)?,
+                "impl_from" => container_attrs.impl_from = parse_flag(meta)?,
+                "impl_default" => {
+                    container_attrs.impl_default = Some(if meta.input.peek(Token![=]) {
+                        Some(meta.value()?.parse::<LitStr>()?.parse()?)
+                    } else {
+                        parse_flag(meta)?;
+                        None
+                    });
+                }
+                "cfg" => {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    container_attrs.cfg = Some(content.parse()?);
+                }
+                "bound" => {
+                    let bound = meta.value()?.parse::<LitStr>()?;
+                    container_attrs.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+                }
+                _ => return Err(unknown_option(meta, Location::Container)),
+            }
+            Ok(())
+        })?;
+
+        // `validate` and `error` only make sense together, since the error type has to be
+        // named in the signature of `try_splat`
+        let find = |name: &str| options.iter().find(|(option, _)| option == name);
+        match (find("validate"), find("error")) {
+            (Some((_, span)), None) => {
+                return Err(Error::new(
+                    *span,
+                    Message::new("`validate` needs the error type returned by the function")
+                        .help("name it with `error = \"...\"`"),
+                ))
+            }
+            (None, Some((_, span))) => {
+                return Err(Error::new(
+                    *span,
+                    "`error` can only be used with `validate`",
+                ))
+            }
+            _ => {}
+        }
+
+        container_attrs.options = options;
+        Ok(container_attrs)
+    }
+
+    // checks whether an extra method builds the struct from fresh values, so it can be
+    // derived for values that aren't Clone
+    pub(crate) fn has_fresh_value_method(&self) -> bool {
+        self.extra
+            .iter()
+            .any(|method| FRESH_VALUE_METHODS.iter().any(|name| method == name))
+    }
+}
+
+// an associated constant given by `const_value`, like `ZEROED = "0"`
+pub(crate) struct ConstValue {
+    pub(crate) name: Ident,
+    pub(crate) value: Expr,
+    // the expression as it was written, for the constant's documentation
+    pub(crate) text: String,
+}
+
+// an inclusive range of values given by `clamp` or `range`, like `0.0..=1.0`, where either
+// end can be left out
+pub(crate) struct ValueRange {
+    pub(crate) start: Option<Expr>,
+    pub(crate) end: Option<Expr>,
+    // the range as it was written, for messages
+    pub(crate) text: String,
+}
+
+impl ValueRange {
+    // builds the condition checking that `v` is in the range
+    pub(crate) fn in_range(&self) -> TokenStream2 {
+        let lower = self.start.iter().map(|start| quote!(#start <= v));
+        let upper = self.end.iter().map(|end| quote!(v <= #end));
+        let conditions = lower.chain(upper);
+        quote!(#(#conditions)&&*)
+    }
+}
+
+// the oldest Rust version the generated code has to compile with
+pub(crate) struct Msrv {
+    pub(crate) version: (u32, u32),
+    pub(crate) span: Span,
+}
+
+// options set by `#[splat(...)]` attributes on a field
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    // `skip` leaves the field out of the splat, filling it with its default
+    pub(crate) skip: bool,
+    // `skip_with = "..."` leaves the field out of the splat, filling it with the expression
+    pub(crate) skip_with: Option<Expr>,
+    // `with = "..."` fills the field by passing the value to the given function
+    pub(crate) with: Option<Path>,
+    // `with_ref = "..."` fills the field by passing a reference to the value to the function
+    pub(crate) with_ref: Option<Path>,
+    // `clone_with = "..."` duplicates the value for the field with the given `fn(&T) -> T`
+    // instead of `Clone::clone`
+    pub(crate) clone_with: Option<Path>,
+    // `nested` fills the field by passing the value to the `splat` of the field's type
+    pub(crate) nested: bool,
+    // `wrap = "..."` passes the field's value to the given functions, like `Box::new`,
+    // outermost first
+    pub(crate) wrap: Vec<Path>,
+    // `cast` fills the field by converting the value with `as`, and `checked_cast` with
+    // `TryFrom`, panicking when it doesn't fit
+    pub(crate) cast: bool,
+    pub(crate) checked_cast: bool,
+    // `into` fills the field by converting the value with `From`
+    pub(crate) into: bool,
+    // `share` clones the handle of an `Rc` or `Arc` field with its own `clone`
+    pub(crate) share: bool,
+    options: Vec<(String, Span)>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut field_attrs = Self::default();
+        let options = parse_splat_attrs(attrs, |meta| {
+            match option_name(meta).as_str() {
+                "skip" => field_attrs.skip = parse_flag(meta)?,
+                "nested" => field_attrs.nested = parse_flag(meta)?,
+                "cast" => field_attrs.cast = parse_flag(meta)?,
+                "checked_cast" => field_attrs.checked_cast = parse_flag(meta)?,
+                "into" => field_attrs.into = parse_flag(meta)?,
+                "share" => field_attrs.share = parse_flag(meta)?,
+                "wrap" => field_attrs.wrap = parse_wrap(meta)?,
+                "with" => field_attrs.with = Some(meta.value()?.parse::<LitStr>()?.parse()?),
+                "with_ref" => {
+                    field_attrs.with_ref = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                }
+                "clone_with" => {
+                    field_attrs.clone_with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                }
+                "skip_with" => {
+                    field_attrs.skip_with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                }
+                _ => return Err(unknown_option(meta, Location::Field)),
+            }
+            Ok(())
+        })?;
+        field_attrs.options = options;
+        Ok(field_attrs)
+    }
+
+    // checks whether the field's value is converted by a function, so the field doesn't need
+    // to have the type taken by the splat
+    pub(crate) fn is_converted(&self) -> bool {
+        self.with.is_some()
+            || self.with_ref.is_some()
+            || self.nested
+            || self.cast
+            || self.checked_cast
+            || self.into
+    }
+
+    // gets the expression filling the field when it is left out of the splat
+    pub(crate) fn skipped_init(&self) -> Option<TokenStream2> {
+        match &self.skip_with {
+            Some(skip_with) => Some(skip_with.to_token_stream()),
+            None => self
+                .skip
+                .then(|| quote!(::core::default::Default::default())),
+        }
+    }
+}
+
+// runs `parse_option` on every option of every `#[splat(...)]` attribute, carrying on past
+// invalid options so that all of their errors are reported together, and returns the name
+// and span of each option that was parsed
+fn parse_splat_attrs(
+    attrs: &[Attribute],
+    mut parse_option: impl FnMut(&ParseNestedMeta) -> Result<()>,
+) -> Result<Vec<(String, Span)>> {
+    let mut options = Vec::new();
+    let mut errors = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("splat")) {
+        // only the list form, `#[splat(...)]`, is accepted
+        if let Err(error) = attr.meta.require_list() {
+            errors.push(error);
+            continue;
+        }
+
+        let result = attr.parse_nested_meta(|meta| {
+            match parse_option(&meta) {
+                Ok(()) => options.push((option_name(&meta), meta.path.span())),
+                Err(error) => {
+                    errors.push(error);
+                    skip_option(&meta)?;
+                }
+            }
+            Ok(())
+        });
+        if let Err(error) = result {
+            errors.push(error);
+        }
+    }
+
+    combine_errors(errors)?;
+    Ok(options)
+}
+
+// builds an error at both options of every repeated or contradictory pair, checking `options`,
+// written at `location`, among themselves and against `container_options`, which are written
+// on the struct and apply to every field
+fn find_conflicts(
+    location: Location,
+    options: &[(String, Span)],
+    container_options: &[(String, Span)],
+) -> Vec<Error> {
+    let conflicts = |table: &[(&str, &str)], a: &str, b: &str| table.contains(&(a, b));
+    let mut errors = Vec::new();
+
+    for (index, (name, span)) in options.iter().enumerate() {
+        for (other_name, other_span) in &options[index + 1..] {
+            if name == other_name {
+                errors.push(Error::new(
+                    *other_span,
+                    Message::new(format!("duplicate splat option `{}`", name))
+                        .explain(EXPLAIN_OPTIONS),
+                ));
+                errors.push(Error::new(*span, format!("`{}` first used here", name)));
+            }
+        }
+
+        let inner_pairs = options[index + 1..]
+            .iter()
+            .filter(|(other_name, _)| {
+                conflicts(location.conflicts(), name, other_name)
+                    || conflicts(location.conflicts(), other_name, name)
+            })
+            .map(|other| (other, ""));
+        let container_pairs = container_options
+            .iter()
+            .filter(|(other_name, _)| conflicts(CONTAINER_FIELD_CONFLICTS, other_name, name))
+            .map(|other| (other, " on the struct, which applies to every field,"));
+        for ((other_name, other_span), context) in inner_pairs.chain(container_pairs) {
+            errors.push(Error::new(
+                *span,
+                Message::new(format!(
+                    "`{}` cannot be combined with `{}`",
+                    name, other_name
+                ))
+                .explain(EXPLAIN_OPTIONS),
+            ));
+            errors.push(Error::new(
+                *other_span,
+                format!("`{}`{} conflicts with `{}`", other_name, context, name),
+            ));
+        }
+    }
+
+    errors
+}
+
+// gets the name an option was written with
+fn option_name(meta: &ParseNestedMeta) -> String {
+    meta.path.to_token_stream().to_string().replace(' ', "")
+}
+
+// parses an option that is either present or not, like `#[splat(flag)]`
+fn parse_flag(meta: &ParseNestedMeta) -> Result<bool> {
+    if meta.input.is_empty() || meta.input.peek(Token![,]) {
+        Ok(true)
+    } else {
+        Err(meta.error(format!("`{}` doesn't take a value", option_name(meta))))
+    }
+}
+
+// parses an option naming an identifier, like `#[splat(option = "name")]`
+fn parse_ident(meta: &ParseNestedMeta) -> Result<Ident> {
+    meta.value()?.parse::<LitStr>()?.parse()
+}
+
+// parses the methods listed by `extra(...)`, reporting every unknown or repeated method
+fn parse_extra(meta: &ParseNestedMeta) -> Result<Vec<Ident>> {
+    let mut methods = Vec::<Ident>::new();
+    let mut errors = Vec::new();
+
+    meta.parse_nested_meta(|method| {
+        let name = option_name(&method);
+        if !EXTRA_METHODS.contains(&name.as_str()) {
+            errors.push(
+                method.error(
+                    Message::new(format!("unknown extra method `{}`", name))
+                        .help(expected_one_of(EXTRA_METHODS))
+                        .explain(EXPLAIN_OPTIONS),
+                ),
+            );
+        } else if methods.iter().any(|other| *other == name) {
+            errors.push(method.error(format!("duplicate extra method `{}`", name)));
+        } else if name == "splat_in" && !cfg!(feature = "bumpalo") {
+            errors.push(
+                method.error(
+                    Message::new("extra method `splat_in` needs the `bumpalo` feature")
+                        .help("enable the `bumpalo` feature of `splat_derive`"),
+                ),
+            );
+        } else {
+            methods.push(method.path.require_ident()?.clone());
+        }
+        skip_option(&method)
+    })?;
+
+    combine_errors(errors)?;
+    Ok(methods)
+}
+
+// parses the constants listed by `const_value(...)`, reporting every repeated name
+fn parse_const_value(meta: &ParseNestedMeta) -> Result<Vec<ConstValue>> {
+    let mut const_values = Vec::<ConstValue>::new();
+    let mut errors = Vec::new();
+
+    meta.parse_nested_meta(|constant| {
+        let name = constant.path.require_ident()?.clone();
+        let value = constant.value()?.parse::<LitStr>()?;
+        if const_values.iter().any(|other| other.name == name) {
+            errors.push(constant.error(format!("duplicate constant `{}`", name)));
+        } else {
+            const_values.push(ConstValue {
+                name,
+                value: value.parse()?,
+                text: value.value(),
+            });
+        }
+        Ok(())
+    })?;
+
+    combine_errors(errors)?;
+    Ok(const_values)
+}
+
+// parses an option giving an inclusive range, like `#[splat(option = "0..=9")]`
+fn parse_range(meta: &ParseNestedMeta) -> Result<ValueRange> {
+    let range = meta.value()?.parse::<LitStr>()?;
+    let (start, end) = range.parse_with(|input: ParseStream| {
+        let start = (!input.peek(Token![..=]))
+            .then(|| input.parse())
+            .transpose()?;
+        input.parse::<Token![..=]>()?;
+        let end = (!input.is_empty()).then(|| input.parse()).transpose()?;
+        Ok((start, end))
+    })?;
+    if start.is_none() && end.is_none() {
+        return Err(Error::new(
+            range.span(),
+            "the range needs a start or an end",
+        ));
+    }
+
+    Ok(ValueRange {
+        start,
+        end,
+        text: range.value(),
+    })
+}
+
+// parses a Rust version, like `#[splat(msrv = "1.56")]`, where the patch version is ignored
+fn parse_msrv(meta: &ParseNestedMeta) -> Result<Msrv> {
+    let msrv = meta.value()?.parse::<LitStr>()?;
+    let value = msrv.value();
+    let mut parts = value.split('.').map(str::parse::<u32>);
+    let version = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), None | Some(Ok(_)), None) => (major, minor),
+        _ => {
+            return Err(Error::new(
+                msrv.span(),
+                Message::new(format!("invalid Rust version `{}`", value))
+                    .help("write it like `1.56`"),
+            ))
+        }
+    };
+    if version < (1, 56) {
+        return Err(Error::new(
+            msrv.span(),
+            "the generated code needs Rust 1.56 or later",
+        ));
+    }
+
+    Ok(Msrv {
+        version,
+        span: msrv.span(),
+    })
+}
+
+// extra methods building the struct from fresh values rather than clones, which don't need
+// the value to be Clone
+const FRESH_VALUE_METHODS: &[&str] = &[
+    "splat_with",
+    "splat_with_index",
+    "splat_with_name",
+    "try_splat_with",
+    "splat_default",
+    "splat_cycle",
+];
+
+// parses the comma separated functions given to `wrap`
+fn parse_wrap(meta: &ParseNestedMeta) -> Result<Vec<Path>> {
+    let wrap = meta.value()?.parse::<LitStr>()?;
+    Ok(wrap
+        .parse_with(Punctuated::<Path, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect())
+}
+
+// consumes whatever is left of an option, so parsing can carry on at the next one
+fn skip_option(meta: &ParseNestedMeta) -> Result<()> {
+    while !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+        meta.input.parse::<TokenTree>()?;
+    }
+    Ok(())
+}
+
+// builds the error for an option that isn't accepted where it was written
+fn unknown_option(meta: &ParseNestedMeta, location: Location) -> Error {
+    let name = option_name(meta);
+    let other = location.other();
+
+    if other.options().contains(&name.as_str()) {
+        return meta.error(
+            Message::new(format!(
+                "`{}` can only be used on {}, not {}",
+                name,
+                other.name(),
+                location.name(),
+            ))
+            .explain(EXPLAIN_OPTIONS),
+        );
+    }
+
+    let message = Message::new(format!("unknown splat option `{}`", name));
+    meta.error(
+        match location.options() {
+            [] => message.note(format!("no options are accepted on {}", location.name())),
+            options => message.help(expected_one_of(options)),
+        }
+        .explain(EXPLAIN_OPTIONS),
+    )
+}
+
+// lists the names that would have been accepted in place of an unknown one
+fn expected_one_of(names: &[&str]) -> String {
+    format!(
+        "expected one of {}",
+        names
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
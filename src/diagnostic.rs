@@ -0,0 +1,136 @@
+use std::fmt::{self, Display};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use syn::Error;
+
+// an error message with notes and help attached as extra lines, which read naturally in a
+// plain compile error and are turned back into proper notes and help by `emit` when the
+// `nightly-diagnostics` feature is enabled
+pub(crate) struct Message(String);
+
+impl Message {
+    pub(crate) fn new(message: impl Display) -> Self {
+        Message(message.to_string())
+    }
+
+    pub(crate) fn note(mut self, note: impl Display) -> Self {
+        self.0 += &format!("\nnote: {}", note);
+        self
+    }
+
+    pub(crate) fn help(mut self, help: impl Display) -> Self {
+        self.0 += &format!("\nhelp: {}", help);
+        self
+    }
+
+    // attaches a longer explanation of the rule that was broken, only with `verbose-errors`
+    pub(crate) fn explain(self, explanation: &str) -> Self {
+        if cfg!(feature = "verbose-errors") {
+            self.note(explanation)
+        } else {
+            self
+        }
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// explanations of each rule, attached to errors with the `verbose-errors` feature
+pub(crate) const EXPLAIN_STRUCTS_ONLY: &str = "`splat` sets every field of a single set of \
+fields to the same value. Enums have a set of fields per variant and unions only ever hold \
+one field, so neither can be splatted.";
+pub(crate) const EXPLAIN_NO_FIELDS: &str = "`splat` takes a value of the type shared by the \
+struct's fields, so a struct needs at least one field that isn't `PhantomData` to derive it.";
+pub(crate) const EXPLAIN_SAME_TYPE: &str = "`splat` takes a single value and clones it into \
+every field, so each field must have the type of that value, or be a tuple or array built only \
+from it. `PhantomData` fields are the exception, and are always filled with `PhantomData`, as \
+are fields marked `#[splat(skip)]` or `#[splat(skip_with = \"...\")]`, which are filled on \
+their own.";
+pub(crate) const EXPLAIN_RECURSIVE: &str = "the parameter of `splat` has the type shared by \
+the struct's fields, so when that type mentions `Self`, an instance of the struct would be \
+needed before `splat` could be called to build one.";
+pub(crate) const EXPLAIN_OPTIONS: &str = "options are written as `#[splat(...)]` on the struct \
+or on individual fields. Each option may only be given once per struct or field, and options \
+with contradicting behavior can't be combined.";
+
+// reports the errors as `compile_error!` invocations
+#[cfg(not(feature = "nightly-diagnostics"))]
+pub(crate) fn emit(errors: Error) -> TokenStream2 {
+    errors.into_compile_error()
+}
+
+// reports the errors through the compiler's diagnostic API, with notes and help attached
+// as children of the error they belong to
+#[cfg(feature = "nightly-diagnostics")]
+pub(crate) fn emit(errors: Error) -> TokenStream2 {
+    use proc_macro::{Diagnostic, Level};
+
+    for error in errors {
+        let message = error.to_string();
+        let mut lines = message.lines();
+        let mut diagnostic = Diagnostic::spanned(
+            error.span().unwrap(),
+            Level::Error,
+            lines.next().unwrap_or_default(),
+        );
+
+        for line in lines {
+            diagnostic = with_child(diagnostic, line);
+        }
+
+        diagnostic.emit();
+    }
+
+    TokenStream2::new()
+}
+
+// reports a warning by using a deprecated constant, since stable proc macros have no way of
+// emitting warnings directly
+#[cfg(not(feature = "nightly-diagnostics"))]
+pub(crate) fn warn(span: Span, message: Message) -> TokenStream2 {
+    use quote::quote_spanned;
+
+    let note = message.to_string();
+    quote_spanned!(span=>
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const splat_warning: () = ();
+            splat_warning
+        };
+    )
+}
+
+// reports a warning through the compiler's diagnostic API
+#[cfg(feature = "nightly-diagnostics")]
+pub(crate) fn warn(span: Span, message: Message) -> TokenStream2 {
+    use proc_macro::{Diagnostic, Level};
+
+    let message = message.to_string();
+    let mut lines = message.lines();
+    let mut diagnostic = Diagnostic::spanned(
+        span.unwrap(),
+        Level::Warning,
+        lines.next().unwrap_or_default(),
+    );
+    for line in lines {
+        diagnostic = with_child(diagnostic, line);
+    }
+    diagnostic.emit();
+
+    TokenStream2::new()
+}
+
+// attaches a line of a `Message` to a diagnostic as a note or help
+#[cfg(feature = "nightly-diagnostics")]
+fn with_child(diagnostic: proc_macro::Diagnostic, line: &str) -> proc_macro::Diagnostic {
+    match (line.strip_prefix("note: "), line.strip_prefix("help: ")) {
+        (Some(note), _) => diagnostic.note(note),
+        (_, Some(help)) => diagnostic.help(help),
+        _ => diagnostic.note(line),
+    }
+}
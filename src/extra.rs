@@ -0,0 +1,2174 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    ext::IdentExt, parse_quote, Error, Expr, Field, GenericArgument, Generics, Ident, Index,
+    Member, Path, PathArguments, Result, Type, Visibility,
+};
+
+use crate::{
+    attr::{ConstValue, FieldAttrs},
+    get_cfgs, get_fill, get_fresh_param, get_unwrapped_type, is_phantom_data, is_same_type,
+    is_splat_field, references_generics, type_to_string,
+};
+
+// the parts of the derive that the extra methods are generated from
+pub(crate) struct Target<'a> {
+    pub(crate) struct_name: &'a Ident,
+    pub(crate) generics: &'a Generics,
+    pub(crate) vis: &'a Visibility,
+    // the type taken by `splat`, and the bounds `splat` puts on it
+    pub(crate) param_type: &'a Type,
+    // the type filled in at the leaves of each field, which is built from the parameter by
+    // the functions given by `wrap`, outermost first, or by the fields' `splat` with `deep`
+    pub(crate) fill_type: &'a Type,
+    pub(crate) wrap: &'a [TokenStream2],
+    // whether arrays are built by mapping an array of units, for toolchains before 1.63
+    pub(crate) array_map: bool,
+    pub(crate) bounds: &'a [TokenStream2],
+    // whether the value is copied into each field rather than cloned
+    pub(crate) copy: bool,
+    // whether the splat takes a reference to the value, which is cloned from it
+    pub(crate) by_ref: bool,
+    // every field of the struct, with the member it is initialized by and its options
+    pub(crate) fields: Vec<(&'a Member, &'a Field, &'a FieldAttrs)>,
+    // the associated function given by `constructor`, which builds the struct instead of a
+    // struct expression
+    pub(crate) constructor: Option<&'a Ident>,
+    // whether the struct is `#[repr(packed)]`, so its fields can't be borrowed
+    pub(crate) packed: bool,
+    // the parameter `v` of the splat, as written in its signature, and the statements turning
+    // it into the value of the parameter type, which methods taking the same input share
+    pub(crate) param: &'a TokenStream2,
+    pub(crate) prelude: &'a TokenStream2,
+    // the statements from `clamp` or `range` in the prelude, which check an owned value `v` of
+    // the parameter type
+    pub(crate) range_check: &'a TokenStream2,
+    // the path given by `crate`, which the crates used outside of `core` and `std` are
+    // reached through
+    pub(crate) crate_path: Option<&'a Path>,
+}
+
+// the value a method fills the fields with
+#[derive(Clone)]
+pub(crate) enum Value {
+    // a Copy value, which is copied into each leaf, so arrays of it can be built by repetition
+    Copied(TokenStream2),
+    // a value which is cloned into each leaf
+    Cloned(TokenStream2),
+    // an expression which is evaluated again for each leaf
+    Fresh(TokenStream2),
+}
+
+impl Value {
+    // gets the expression filling a single leaf, duplicating the value with the field's
+    // `clone_with` function if it has one
+    fn leaf(&self, clone_with: Option<&TokenStream2>) -> TokenStream2 {
+        match (self, clone_with) {
+            (Value::Fresh(value), _) | (Value::Copied(value), None) => value.clone(),
+            (Value::Copied(value) | Value::Cloned(value), Some(clone_with)) => {
+                quote!(#clone_with(&#value))
+            }
+            (Value::Cloned(value), None) => quote!(#value.clone()),
+        }
+    }
+
+    // gets a reference to the value, without cloning it
+    fn by_ref(&self) -> TokenStream2 {
+        match self {
+            Value::Copied(value) | Value::Cloned(value) | Value::Fresh(value) => quote!(&#value),
+        }
+    }
+
+    // checks whether arrays of the leaf can be built by repetition, which `clone_with` rules
+    // out since its function is called for every leaf
+    fn repeat(&self, clone_with: Option<&TokenStream2>) -> bool {
+        matches!(self, Value::Copied(_)) && clone_with.is_none()
+    }
+}
+
+impl Target<'_> {
+    // gets the parameter `v` of the splat, which is copied or cloned into each field, and is
+    // dereferenced when the splat takes a reference, since copies are taken out of it up front
+    pub(crate) fn param_value(&self) -> Value {
+        if self.copy {
+            Value::Copied(quote!(v))
+        } else if self.by_ref {
+            Value::Cloned(quote!((*v)))
+        } else {
+            Value::Cloned(quote!(v))
+        }
+    }
+
+    // gets an owned value `v` of the parameter type, which is copied or cloned into each field
+    fn owned_value(&self) -> Value {
+        if self.copy {
+            Value::Copied(quote!(v))
+        } else {
+            Value::Cloned(quote!(v))
+        }
+    }
+
+    // builds the struct with every field filled with `value`, or passes the fills to the
+    // constructor in field order
+    pub(crate) fn construct(&self, value: &Value) -> TokenStream2 {
+        self.construct_each(|_, _| value.clone())
+    }
+
+    // builds the struct like `construct`, with each field filled with the value given for its
+    // index and member
+    fn construct_each(&self, value: impl Fn(usize, &Member) -> Value) -> TokenStream2 {
+        if let Some(constructor) = self.constructor {
+            let args = self
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, field, _))| !is_phantom_data(&field.ty))
+                .map(|(index, (member, field, attrs))| {
+                    self.init(field, attrs, &value(index, member))
+                });
+            return quote!(Self::#constructor(#(#args),*));
+        }
+
+        let inits = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, (member, field, attrs))| {
+                let cfgs = get_cfgs(field);
+                let init = self.init(field, attrs, &value(index, member));
+                quote!(#(#cfgs)* #member: #init)
+            });
+
+        quote!(Self { #(#inits),* })
+    }
+
+    // gets the expression initializing a field, which is PhantomData for PhantomData fields
+    // and given by the field's options for skipped fields, which don't take part in the splat
+    fn init(&self, field: &Field, attrs: &FieldAttrs, value: &Value) -> TokenStream2 {
+        if is_phantom_data(&field.ty) {
+            quote!(::core::marker::PhantomData)
+        } else if let Some(skipped_init) = attrs.skipped_init() {
+            skipped_init
+        } else {
+            self.fill(field, attrs, value)
+        }
+    }
+
+    // gets the expression filling a field that takes part in the splat with `value`, which
+    // is passed to the field's `with` function or its type's `splat`, or cast or converted to
+    // its type, if the field asks for it, or otherwise through the `wrap` functions into every leaf
+    pub(crate) fn fill(&self, field: &Field, attrs: &FieldAttrs, value: &Value) -> TokenStream2 {
+        // `share` clones the pointer with its own `clone`, like `Rc::clone(&v)`
+        let clone_with = match &attrs.clone_with {
+            Some(clone_with) => Some(clone_with.to_token_stream()),
+            None => attrs.share.then(|| {
+                let ty = &field.ty;
+                quote!(<#ty>::clone)
+            }),
+        };
+        let clone_with = clone_with.as_ref();
+        if let Some(with) = &attrs.with {
+            let leaf = value.leaf(clone_with);
+            return quote!(#with(#leaf));
+        }
+        if attrs.nested {
+            let ty = &field.ty;
+            let leaf = value.leaf(clone_with);
+            return quote!(<#ty>::splat(#leaf));
+        }
+        if attrs.cast {
+            let ty = &field.ty;
+            let leaf = value.leaf(clone_with);
+            return quote!((#leaf) as #ty);
+        }
+        if attrs.checked_cast {
+            let ty = &field.ty;
+            let leaf = value.leaf(clone_with);
+            let message = match &field.ident {
+                Some(ident) => format!("splat value out of range for field `{}`", ident),
+                None => String::from("splat value out of range for a field"),
+            };
+            return quote!(
+                match <#ty as ::core::convert::TryFrom<_>>::try_from(#leaf) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => ::core::panic!(#message),
+                }
+            );
+        }
+        if attrs.into {
+            let ty = &field.ty;
+            let leaf = value.leaf(clone_with);
+            return quote!(<#ty as ::core::convert::From<_>>::from(#leaf));
+        }
+        if let Some(with_ref) = &attrs.with_ref {
+            let value = value.by_ref();
+            return quote!(#with_ref(#value));
+        }
+
+        // the field's own wrappers are applied to the whole field, outside of the struct's
+        let ty = get_unwrapped_type(&field.ty, &attrs.wrap).expect("checked by the splat");
+        let leaf = value.leaf(clone_with);
+        let fill = if self.wrap.is_empty() {
+            get_fill(
+                ty,
+                self.fill_type,
+                &leaf,
+                value.repeat(clone_with),
+                self.array_map,
+            )
+        } else {
+            // the wrappers are called for every leaf, so their result is never repeated
+            let wrapped = self
+                .wrap
+                .iter()
+                .rev()
+                .fold(leaf, |leaf, wrap| quote!(#wrap(#leaf)));
+            get_fill(ty, self.fill_type, &wrapped, false, self.array_map)
+        };
+        attrs
+            .wrap
+            .iter()
+            .rev()
+            .fold(fill, |fill, wrap| quote!(#wrap(#fill)))
+    }
+
+    // gets the number of leaves a fresh value is evaluated for when the struct is built, where
+    // fields that aren't filled leaf by leaf are a single leaf
+    fn leaf_count(&self) -> TokenStream2 {
+        let counts = self
+            .fields
+            .iter()
+            .filter(|(_, field, attrs)| is_splat_field(field, attrs))
+            .map(|(_, field, attrs)| {
+                let cfgs = get_cfgs(field);
+                let count = if attrs.is_converted() {
+                    quote!(1)
+                } else {
+                    let ty =
+                        get_unwrapped_type(&field.ty, &attrs.wrap).expect("checked by the splat");
+                    get_leaf_count(ty, self.fill_type)
+                };
+                quote!(#(#cfgs)* { count += #count; })
+            });
+
+        quote!({
+            let mut count = 0usize;
+            #(#counts)*
+            count
+        })
+    }
+
+    // checks whether any field is filled through an array, whose elements are built by a
+    // closure
+    fn fills_arrays(&self) -> bool {
+        fn has_array(ty: &Type) -> bool {
+            match ty {
+                Type::Array(_) => true,
+                Type::Group(group) => has_array(&group.elem),
+                Type::Paren(paren) => has_array(&paren.elem),
+                Type::Tuple(tuple) => tuple.elems.iter().any(has_array),
+                _ => false,
+            }
+        }
+
+        self.fields.iter().any(|(_, field, attrs)| {
+            is_splat_field(field, attrs) && !attrs.is_converted() && has_array(&field.ty)
+        })
+    }
+
+    // gets the number of values visited by `visit_leaves` as a constant expression, for the
+    // length of an array, which can't depend on `#[cfg]` attributes, and can only be a const
+    // generic parameter when the struct is a single array of that length
+    fn visited_leaf_count(&self) -> Result<TokenStream2> {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|(_, field, attrs)| {
+                is_splat_field(field, attrs) && !attrs.is_converted() && attrs.wrap.is_empty()
+            })
+            .collect::<Vec<_>>();
+        if let Some(cfg) = fields
+            .iter()
+            .find_map(|(_, field, _)| get_cfgs(field).pop())
+        {
+            return Err(Error::new_spanned(
+                cfg,
+                "the number of values in the fields can't depend on `#[cfg]`",
+            ));
+        }
+
+        let counts = fields
+            .iter()
+            .map(|(_, field, _)| get_leaf_count(&field.ty, self.fill_type))
+            .collect::<Vec<_>>();
+        let is_single_array = matches!(
+            fields.as_slice(),
+            [(_, field, _)] if matches!(
+                &field.ty,
+                Type::Array(array) if is_same_type(&array.elem, self.fill_type)
+            )
+        );
+        if !is_single_array {
+            if let Some((_, field, _)) = fields
+                .iter()
+                .find(|(_, field, _)| has_generic_len(&field.ty, self.generics))
+            {
+                return Err(Error::new_spanned(
+                    &field.ty,
+                    "the number of values in the fields can only depend on a const generic parameter when the struct is a single array",
+                ));
+            }
+        }
+
+        Ok(match counts.split_first() {
+            Some((first, rest)) => quote!(#first #(+ #rest)*),
+            None => quote!(0),
+        })
+    }
+
+    // gets the statements setting every field of `self` to the value of the splat, where
+    // fields holding the value itself are cloned into with `clone_from` so that their
+    // allocations are reused
+    fn assignments(&self) -> Vec<TokenStream2> {
+        self.assignments_of(&self.param_value())
+    }
+
+    // gets the statements setting every field of `self` like `assignments`, to `value`
+    fn assignments_of(&self, value: &Value) -> Vec<TokenStream2> {
+        // with a constructor, the whole struct is replaced so its invariants are upheld
+        match self.constructor {
+            Some(_) => {
+                let construct = self.construct(value);
+                vec![quote!(*self = #construct;)]
+            }
+            None => self
+                .fields
+                .iter()
+                .filter(|(_, field, attrs)| is_splat_field(field, attrs))
+                .map(|(member, field, attrs)| {
+                    // attributes are only allowed on block statements, not on assignments
+                    let cfgs = get_cfgs(field);
+                    if self.is_clone_from_field(field, attrs, value) {
+                        let source = value.by_ref();
+                        return quote!(#(#cfgs)* {
+                            ::core::clone::Clone::clone_from(&mut self.#member, #source);
+                        });
+                    }
+                    let fill = self.fill(field, attrs, value);
+                    quote!(#(#cfgs)* { self.#member = #fill; })
+                })
+                .collect(),
+        }
+    }
+
+    // checks whether a field can be filled in place with `Clone::clone_from`, which needs the
+    // value to be cloned into the field as it is, and the field to be borrowed
+    fn is_clone_from_field(&self, field: &Field, attrs: &FieldAttrs, value: &Value) -> bool {
+        matches!(value, Value::Cloned(_))
+            && !self.packed
+            && self.wrap.is_empty()
+            && self.is_plain_field(attrs)
+            && is_same_type(&field.ty, self.fill_type)
+    }
+
+    // gets the statements writing every field of the struct behind the pointer `ptr` to the
+    // value, where arrays holding the value are written one element at a time so they aren't
+    // built on the stack, which must be in an unsafe block where `ptr` is valid for writes
+    fn writes(&self, value: &Value) -> Vec<TokenStream2> {
+        // fields of packed structs may be unaligned
+        let write = if self.packed {
+            quote!(write_unaligned)
+        } else {
+            quote!(write)
+        };
+        self.fields
+            .iter()
+            .map(|(member, field, attrs)| {
+                let cfgs = get_cfgs(field);
+                let place = quote!(::core::ptr::addr_of_mut!((*ptr).#member));
+                let write_field = match &field.ty {
+                    Type::Array(array)
+                        if is_splat_field(field, attrs)
+                            && self.is_plain_field(attrs)
+                            && self.wrap.is_empty()
+                            && is_same_type(&array.elem, self.fill_type) =>
+                    {
+                        let elem = &array.elem;
+                        let len = &array.len;
+                        let leaf = value.leaf(None);
+                        quote!(
+                            let elems = #place.cast::<#elem>();
+                            for index in 0..#len {
+                                elems.add(index).#write(#leaf);
+                            }
+                        )
+                    }
+                    _ => {
+                        let init = self.init(field, attrs, value);
+                        quote!(#place.#write(#init);)
+                    }
+                };
+                quote!(#(#cfgs)* { #write_field })
+            })
+            .collect()
+    }
+
+    // gets the statements initializing the struct in `dest`, a `&mut MaybeUninit<Self>`, and
+    // evaluating to it as a `&mut Self`
+    fn write_into_dest(&self) -> TokenStream2 {
+        let value = self.param_value();
+        // a constructor builds the struct itself, so it can only be moved into the destination
+        if self.constructor.is_some() {
+            let construct = self.construct(&value);
+            return quote!(dest.write(#construct));
+        }
+        let writes = self.writes(&value);
+        quote!(
+            let ptr = dest.as_mut_ptr();
+            // SAFETY: `ptr` comes from a unique borrow of the destination, which has the layout
+            // of `Self`, and every field of the struct is written through it before the
+            // destination is borrowed as an initialized `Self`
+            unsafe {
+                #(#writes)*
+                &mut *ptr
+            }
+        )
+    }
+
+    // gets `visit` for every value in the fields filled leaf by leaf, borrowing arrays mutably
+    // or not to loop over them, where the fields of `#[repr(packed)]` structs are copied out,
+    // and back in when they're borrowed mutably, since they can't be borrowed in place
+    fn visit_leaves(
+        &self,
+        mutable: bool,
+        visit: &dyn Fn(&TokenStream2) -> TokenStream2,
+    ) -> Vec<TokenStream2> {
+        let borrow = if mutable { quote!(&mut) } else { quote!(&) };
+        self.fields
+            .iter()
+            .filter(|(_, field, attrs)| {
+                is_splat_field(field, attrs) && !attrs.is_converted() && attrs.wrap.is_empty()
+            })
+            .filter_map(|(member, field, _)| {
+                let cfgs = get_cfgs(field);
+                let visits = if self.packed {
+                    let visits =
+                        for_each_leaf(&field.ty, self.fill_type, quote!(field), &borrow, visit)?;
+                    let store = mutable.then(|| quote!(self.#member = field;));
+                    let binding = if mutable {
+                        quote!(let mut)
+                    } else {
+                        quote!(let)
+                    };
+                    quote!(
+                        #binding field = self.#member;
+                        #visits
+                        #store
+                    )
+                } else {
+                    for_each_leaf(
+                        &field.ty,
+                        self.fill_type,
+                        quote!(self.#member),
+                        &borrow,
+                        visit,
+                    )?
+                };
+                Some(quote!(#(#cfgs)* { #visits }))
+            })
+            .collect()
+    }
+
+    // checks whether a field has no options changing how the value is put into it
+    fn is_plain_field(&self, attrs: &FieldAttrs) -> bool {
+        attrs.wrap.is_empty() && !attrs.is_converted() && attrs.clone_with.is_none() && !attrs.share
+    }
+
+    // gets the bound needed for the parameter type to implement `bound_trait`
+    fn bound(&self, bound_trait: TokenStream2) -> TokenStream2 {
+        self.bound_on(self.param_type, bound_trait)
+    }
+
+    // gets the bound needed for `ty` to implement `bound_trait`, which is made higher-ranked
+    // when the type isn't generic, so that it is only checked where the method is used
+    fn bound_on(&self, ty: &Type, bound_trait: TokenStream2) -> TokenStream2 {
+        if references_generics(ty, self.generics) {
+            quote!(#ty: #bound_trait)
+        } else {
+            quote!(for<'__splat> #ty: #bound_trait)
+        }
+    }
+}
+
+// generates the extra method with the given name, which `attr` has checked is one of
+// `EXTRA_METHODS`
+pub(crate) fn expand_extra(method: &Ident, target: &Target) -> TokenStream2 {
+    match method.to_string().as_str() {
+        "fill" => expand_fill(target),
+        "splat_with" => expand_splat_with(target),
+        "splat_default" => expand_splat_default(target),
+        "splat_with_index" => expand_splat_with_index(target),
+        "splat_with_name" => expand_splat_with_name(target),
+        "try_splat_with" => expand_try_splat_with(target),
+        "update_all" => expand_update_all(target),
+        "set_all" => expand_set_all(target),
+        "splat_boxed" => expand_splat_boxed(target),
+        "splat_uninit" => expand_splat_uninit(target),
+        "splat_in" => expand_splat_in(target),
+        "splat_many" => expand_splat_many(target),
+        "splat_seq" => expand_splat_seq(target),
+        "splat_gradient" => expand_splat_gradient(target),
+        "splat_cycle" => expand_splat_cycle(target),
+        "copy_from_slice" => expand_copy_from_slice(target),
+        "try_splat_parse" => expand_try_splat_parse(target),
+        "is_splat" => expand_is_splat(target),
+        "splat_value" => expand_splat_value(target),
+        "eq_splat" => expand_eq_splat(target),
+        "to_ref_array" => expand_to_array(target, false),
+        "to_mut_array" => expand_to_array(target, true),
+        "scale_all" => expand_assign_all(
+            target,
+            "scale_all",
+            quote!(MulAssign),
+            quote!(mul_assign),
+            "Multiplies",
+        ),
+        "add_all" => expand_assign_all(
+            target,
+            "add_all",
+            quote!(AddAssign),
+            quote!(add_assign),
+            "Offsets",
+        ),
+        "replace_all" => expand_replace_all(target),
+        "splat_then" => expand_splat_then(target),
+        "with_all" => expand_with_all(target),
+        _ => unreachable!("unchecked extra method `{}`", method),
+    }
+}
+
+// `fill(&mut self, v: T)` sets every field of an existing instance, taking the same
+// parameter as the splat
+fn expand_fill(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let assignments = target.assignments();
+    let doc = format!(
+        " Sets every field of the `{}` to the given `{}`.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn fill(&mut self, #param)
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            #(#assignments)*
+        }
+    )
+}
+
+// `with_all(self, v: T) -> Self` sets every field of the instance and returns it, taking the
+// same parameter as the splat, for call chains without a mutable binding
+fn expand_with_all(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let assignments = target.assignments();
+    let doc = format!(
+        " Returns the `{}` with every field set to the given `{}`.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn with_all(mut self, #param) -> Self
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            #(#assignments)*
+            self
+        }
+    )
+}
+
+// `splat_then(v: T, f: impl FnOnce(&mut Self)) -> Self` splats the value, taking the same
+// parameter as the splat, and lets `f` adjust the result before returning it
+fn expand_splat_then(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let construct = target.construct(&target.param_value());
+    let doc = format!(
+        " Creates a `{}` with every field set to the given `{}`, then passes it to `f` to adjust before returning it.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_then(#param, f: impl ::core::ops::FnOnce(&mut Self)) -> Self
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            let mut splat = #construct;
+            f(&mut splat);
+            splat
+        }
+    )
+}
+
+// `replace_all(&mut self, v: T) -> Self` replaces the instance with one splatted from the
+// value, taking the same parameter as the splat, and returns the previous instance
+fn expand_replace_all(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let construct = target.construct(&target.param_value());
+    let doc = format!(
+        " Sets every field of the `{}` to the given `{}`, returning the previous `{}`.",
+        struct_name,
+        type_to_string(param_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn replace_all(&mut self, #param) -> Self
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            ::core::mem::replace(self, #construct)
+        }
+    )
+}
+
+// `copy_from_slice(&mut self, src: &[T])` and `try_copy_from_slice(&mut self, src: &[T]) ->
+// Result<(), usize>` set the fields in declaration order to the values of a slice holding one
+// value for each leaf, like the slice methods of the same name
+fn expand_copy_from_slice(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let bound = target.bound(quote!(::core::marker::Copy));
+    let leaf_count = target.leaf_count();
+    let assignments = target.assignments_of(&Value::Fresh(quote!(next())));
+    let doc = format!(
+        " Sets the fields of the `{}` to the `{}`s in `src`, in declaration order.",
+        struct_name,
+        type_to_string(param_type),
+    );
+    let try_doc = format!(
+        " Sets the fields of the `{}` to the `{}`s in `src`, in declaration order, if it holds one for each of them.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `src` doesn't hold exactly one value for each of the fields.
+        #[inline]
+        #vis fn copy_from_slice(&mut self, src: &[#param_type])
+        where
+            #bound,
+        {
+            if let ::core::result::Result::Err(len) = self.try_copy_from_slice(src) {
+                ::core::panic!(
+                    "source slice length ({}) does not match the number of values ({})",
+                    src.len(),
+                    len,
+                );
+            }
+        }
+
+        #[doc = #try_doc]
+        ///
+        /// # Errors
+        ///
+        /// Returns the number of values needed, leaving the fields as they were, if `src`
+        /// doesn't hold exactly that many.
+        #[inline]
+        #vis fn try_copy_from_slice(&mut self, src: &[#param_type]) -> ::core::result::Result<(), usize>
+        where
+            #bound,
+        {
+            let len = #leaf_count;
+            if src.len() != len {
+                return ::core::result::Result::Err(len);
+            }
+            let mut index = 0;
+            let mut next = || {
+                let value = src[index];
+                index += 1;
+                value
+            };
+            #(#assignments)*
+            ::core::result::Result::Ok(())
+        }
+    )
+}
+
+// `try_splat_parse(s: &str) -> Result<Self, T::Err>` parses the value from a string once, and
+// splats it
+fn expand_try_splat_parse(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        range_check,
+        ..
+    } = target;
+    let bound = target.bound(quote!(::core::str::FromStr));
+    let construct = target.construct(&target.owned_value());
+    let doc = format!(
+        " Creates a `{}` with every field set to the `{}` parsed from `s`, returning the error from parsing if it fails.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn try_splat_parse(
+            s: &str,
+        ) -> ::core::result::Result<Self, <#param_type as ::core::str::FromStr>::Err>
+        where
+            #(#bounds,)*
+            #bound,
+        {
+            let v = <#param_type as ::core::str::FromStr>::from_str(s)?;
+            #range_check
+            ::core::result::Result::Ok(#construct)
+        }
+    )
+}
+
+// `splat_boxed(v: T) -> Box<Self>` builds the struct directly on the heap, writing its fields
+// into the allocation so no large temporary is built on the stack
+fn expand_splat_boxed(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let value = target.param_value();
+    // a constructor builds the struct itself, so it can only be moved into the box
+    let body = if target.constructor.is_some() {
+        let construct = target.construct(&value);
+        quote!(::std::boxed::Box::new(#construct))
+    } else {
+        let writes = target.writes(&value);
+        quote!(
+            let mut boxed = ::std::boxed::Box::new(::core::mem::MaybeUninit::<Self>::uninit());
+            let ptr = boxed.as_mut_ptr();
+            // SAFETY: `ptr` points to the allocation owned by `boxed`, every field of the
+            // struct is written through it before it is read, and `MaybeUninit<Self>` has the
+            // layout of `Self`, so the box holds an initialized `Self` once it is cast back
+            unsafe {
+                #(#writes)*
+                ::std::boxed::Box::from_raw(::std::boxed::Box::into_raw(boxed).cast::<Self>())
+            }
+        )
+    };
+    let doc = format!(
+        " Creates a boxed `{}` with every field set to the given `{}`, writing the fields straight into the allocation.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_boxed(#param) -> ::std::boxed::Box<Self>
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            #body
+        }
+    )
+}
+
+// `splat_uninit(dest: &mut MaybeUninit<Self>, v: T) -> &mut Self` initializes memory the
+// caller has already set aside, writing the fields into it in place
+fn expand_splat_uninit(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let body = target.write_into_dest();
+    let doc = format!(
+        " Initializes the `{}` in `dest` with every field set to the given `{}`, writing the fields in place. Any value already in `dest` is overwritten without being dropped.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn splat_uninit(dest: &mut ::core::mem::MaybeUninit<Self>, #param) -> &mut Self
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            #body
+        }
+    )
+}
+
+// `splat_in(arena: &Bump, v: T) -> &mut Self` builds the struct in a `bumpalo` arena, writing
+// its fields into the arena's memory in place
+fn expand_splat_in(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let body = target.write_into_dest();
+    let bumpalo = match target.crate_path {
+        Some(crate_path) => quote!(#crate_path::bumpalo),
+        None => quote!(::bumpalo),
+    };
+    let doc = format!(
+        " Allocates a `{}` in the arena with every field set to the given `{}`, writing the fields straight into the arena. Like every value in a `Bump`, it is never dropped.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #[allow(clippy::mut_from_ref)]
+        #vis fn splat_in<'__bump>(arena: &'__bump #bumpalo::Bump, #param) -> &'__bump mut Self
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            let dest = arena.alloc(::core::mem::MaybeUninit::<Self>::uninit());
+            #body
+        }
+    )
+}
+
+// `splat_many(v: T, n: usize) -> Vec<Self>` splats `n` instances from the value, taking the same
+// parameter as the splat, so the struct itself doesn't need to be Clone
+fn expand_splat_many(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    let construct = target.construct(&target.param_value());
+    let doc = format!(
+        " Creates `n` instances of `{}` with every field set to the given `{}`.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_many(#param, n: usize) -> ::std::vec::Vec<Self>
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            (0..n).map(|_| #construct).collect()
+        }
+    )
+}
+
+// `splat_with(f: impl FnMut() -> T) -> Self` calls `f` once per field, so it needs no bounds
+fn expand_splat_with(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let construct = target.construct(&Value::Fresh(quote!(f())));
+    let doc = format!(
+        " Creates a `{}` with every field set to a `{}` returned by `f`, which is called once per field.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_with(mut f: impl ::core::ops::FnMut() -> #param_type) -> Self {
+            #construct
+        }
+    )
+}
+
+// `splat_with_index(f: impl FnMut(usize) -> T) -> Self` calls `f` with the index of each
+// field in the declaration
+fn expand_splat_with_index(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let construct = target.construct_each(|index, _| Value::Fresh(quote!(f(#index))));
+    let doc = format!(
+        " Creates a `{}` with every field set to the `{}` returned by `f` for the field's index.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_with_index(mut f: impl ::core::ops::FnMut(usize) -> #param_type) -> Self {
+            #construct
+        }
+    )
+}
+
+// `splat_with_name(f: impl FnMut(&'static str) -> T) -> Self` calls `f` with the name of
+// each field, or its index for tuple structs
+fn expand_splat_with_name(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let construct = target.construct_each(|_, member| {
+        let name = match member {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        Value::Fresh(quote!(f(#name)))
+    });
+    let doc = format!(
+        " Creates a `{}` with every field set to the `{}` returned by `f` for the field's name.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_with_name(
+            mut f: impl ::core::ops::FnMut(&'static str) -> #param_type,
+        ) -> Self {
+            #construct
+        }
+    )
+}
+
+// `try_splat_with(f: impl FnMut() -> Result<T, E>) -> Result<Self, E>` calls `f` once per
+// field like `splat_with`, returning the first error it gives
+fn expand_try_splat_with(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        generics,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let error = get_fresh_param(generics, "E");
+    // arrays are built by closures returning each element, where `?` can't return the error,
+    // so with array fields every value is taken from `f` up front
+    let body = if target.fills_arrays() {
+        let leaf_count = target.leaf_count();
+        let construct = target.construct(&Value::Fresh(quote!(next())));
+        quote!(
+            let len = #leaf_count;
+            let mut values = ::std::vec::Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push(f()?);
+            }
+            let mut values = ::core::iter::IntoIterator::into_iter(values);
+            let mut next = || match ::core::iter::Iterator::next(&mut values) {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => ::core::unreachable!(),
+            };
+            ::core::result::Result::Ok(#construct)
+        )
+    } else {
+        let construct = target.construct(&Value::Fresh(quote!(f()?)));
+        quote!(::core::result::Result::Ok(#construct))
+    };
+    let doc = format!(
+        " Creates a `{}` with every field set to a `{}` returned by `f`, which is called once per field, returning the first error it returns.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn try_splat_with<#error>(
+            mut f: impl ::core::ops::FnMut() -> ::core::result::Result<#param_type, #error>,
+        ) -> ::core::result::Result<Self, #error> {
+            #body
+        }
+    )
+}
+
+// `splat_seq(start: T, step: T) -> Self` fills the fields in declaration order with `start`,
+// `start + step`, `start + step + step` and so on, only adding the steps that are used so the
+// sequence can end at the largest value of the type
+fn expand_splat_seq(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let clone_bound = target.bound(quote!(::core::clone::Clone));
+    let add_bound = target.bound(quote!(::core::ops::Add<Output = #param_type>));
+    let construct = target.construct(&Value::Fresh(quote!(next())));
+    let doc = format!(
+        " Creates a `{}` with its fields set to `start`, `start + step`, `start + step + step` and so on, in declaration order.",
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_seq(start: #param_type, step: #param_type) -> Self
+        where
+            #clone_bound,
+            #add_bound,
+        {
+            let mut prev = ::core::option::Option::<#param_type>::None;
+            let mut next = || {
+                let value = match &prev {
+                    ::core::option::Option::Some(prev) => {
+                        ::core::clone::Clone::clone(prev) + ::core::clone::Clone::clone(&step)
+                    }
+                    ::core::option::Option::None => ::core::clone::Clone::clone(&start),
+                };
+                prev = ::core::option::Option::Some(::core::clone::Clone::clone(&value));
+                value
+            };
+            #construct
+        }
+    )
+}
+
+// `splat_gradient(start: T, end: T) -> Self` fills the fields in declaration order with values
+// evenly spaced from `start` to `end`, converting the positions to `T` with `From<u16>`, which
+// floats implement
+fn expand_splat_gradient(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let bounds = [
+        quote!(::core::clone::Clone),
+        quote!(::core::convert::From<u16>),
+        quote!(::core::ops::Add<Output = #param_type>),
+        quote!(::core::ops::Sub<Output = #param_type>),
+        quote!(::core::ops::Mul<Output = #param_type>),
+        quote!(::core::ops::Div<Output = #param_type>),
+    ]
+    .map(|bound| target.bound(bound));
+    let leaf_count = target.leaf_count();
+    let construct = target.construct(&Value::Fresh(quote!(next())));
+    let doc = format!(
+        " Creates a `{}` with its fields set to values evenly spaced from `start` to `end`, in declaration order.",
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        ///
+        /// # Panics
+        ///
+        /// Panics if the struct holds more than 65536 values.
+        #[inline]
+        #[must_use]
+        #vis fn splat_gradient(start: #param_type, end: #param_type) -> Self
+        where
+            #(#bounds,)*
+        {
+            let last = #leaf_count.saturating_sub(1);
+            let last_value = match <u16 as ::core::convert::TryFrom<usize>>::try_from(last) {
+                ::core::result::Result::Ok(last) => <#param_type as ::core::convert::From<u16>>::from(last),
+                ::core::result::Result::Err(_) => {
+                    ::core::panic!("`splat_gradient` can't fill more than 65536 values")
+                }
+            };
+            let mut index = 0usize;
+            let mut next = || {
+                // the ends are given exactly, rather than being computed with rounding
+                let value = if index == 0 {
+                    ::core::clone::Clone::clone(&start)
+                } else if index == last {
+                    ::core::clone::Clone::clone(&end)
+                } else {
+                    let position = <#param_type as ::core::convert::From<u16>>::from(index as u16);
+                    let range = ::core::clone::Clone::clone(&end) - ::core::clone::Clone::clone(&start);
+                    ::core::clone::Clone::clone(&start)
+                        + range * position / ::core::clone::Clone::clone(&last_value)
+                };
+                index += 1;
+                value
+            };
+            #construct
+        }
+    )
+}
+
+// `splat_cycle(iter: impl IntoIterator<Item = T>) -> Self` fills the fields in declaration
+// order with the values of the iterator, starting over from a clone of it when it runs out
+fn expand_splat_cycle(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        generics,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let iter = get_fresh_param(generics, "I");
+    let construct = target.construct(&Value::Fresh(quote!(next())));
+    let doc = format!(
+        " Creates a `{}` with its fields set to the values of `iter` in declaration order, starting over when it runs out.",
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `iter` is empty.
+        #[inline]
+        #[must_use]
+        #vis fn splat_cycle<#iter>(iter: #iter) -> Self
+        where
+            #iter: ::core::iter::IntoIterator<Item = #param_type>,
+            #iter::IntoIter: ::core::clone::Clone,
+        {
+            let mut values = ::core::iter::Iterator::cycle(::core::iter::IntoIterator::into_iter(iter));
+            let mut next = || match ::core::iter::Iterator::next(&mut values) {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => {
+                    ::core::panic!("`splat_cycle` was given an empty iterator")
+                }
+            };
+            #construct
+        }
+    )
+}
+
+// `splat_default() -> Self` fills every field with `T::default()`
+fn expand_splat_default(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let bound = target.bound(quote!(::core::default::Default));
+    let construct = target.construct(&Value::Fresh(quote!(
+        <#param_type as ::core::default::Default>::default()
+    )));
+    let doc = format!(
+        " Creates a `{}` with every field set to the default `{}`.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_default() -> Self
+        where
+            #bound
+        {
+            #construct
+        }
+    )
+}
+
+// `splat_some(v: T) -> Self` and `splat_none() -> Self`, generated when `splat` takes an
+// `Option<T>`, fill every field with `Some` of the value or with `None`
+pub(crate) fn expand_option(target: &Target) -> Option<TokenStream2> {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        copy,
+        ..
+    } = target;
+    let some_type = get_option_type(param_type)?;
+    let (some_value, bound) = if *copy {
+        (
+            quote!(::core::option::Option::Some(v)),
+            target.bound_on(some_type, quote!(::core::marker::Copy)),
+        )
+    } else {
+        (
+            quote!(::core::option::Option::Some(::core::clone::Clone::clone(
+                &v
+            ))),
+            target.bound_on(some_type, quote!(::core::clone::Clone)),
+        )
+    };
+    let construct_some = target.construct(&Value::Fresh(some_value));
+    let construct_none = target.construct(&Value::Fresh(quote!(::core::option::Option::None)));
+    let some_doc = format!(
+        " Creates a `{}` with every field set to `Some` of the given `{}`.",
+        struct_name,
+        type_to_string(some_type),
+    );
+    let none_doc = format!(
+        " Creates a `{}` with every field set to `None`.",
+        struct_name
+    );
+
+    Some(quote!(
+        #[doc = #some_doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_some(v: #some_type) -> Self
+        where
+            #bound
+        {
+            #construct_some
+        }
+
+        #[doc = #none_doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_none() -> Self {
+            #construct_none
+        }
+    ))
+}
+
+// gets `T` from `Option<T>`, however the path to `Option` is written
+fn get_option_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if type_path.qself.is_some() || segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(ty) if arguments.args.len() == 1 => Some(ty),
+        _ => None,
+    }
+}
+
+// how a value is stored into a field with interior mutability through a shared reference
+enum Setter {
+    Cell,
+    RefCell,
+    Atomic,
+    Mutex,
+    RwLock,
+}
+
+impl Setter {
+    fn from_type(ty: &Type) -> Option<Setter> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        let name = type_path.path.segments.last()?.ident.to_string();
+        match name.as_str() {
+            "Cell" => Some(Setter::Cell),
+            "RefCell" => Some(Setter::RefCell),
+            "Mutex" => Some(Setter::Mutex),
+            "RwLock" => Some(Setter::RwLock),
+            _ if name.starts_with("Atomic") => Some(Setter::Atomic),
+            _ => None,
+        }
+    }
+
+    // stores `value` into `place`, ignoring poisoning since the whole value is replaced
+    fn set(&self, place: &TokenStream2, value: &TokenStream2) -> TokenStream2 {
+        match self {
+            Setter::Cell => quote!(#place.set(#value);),
+            Setter::RefCell => quote!(*#place.borrow_mut() = #value;),
+            Setter::Atomic => {
+                quote!(#place.store(#value, ::core::sync::atomic::Ordering::SeqCst);)
+            }
+            Setter::Mutex => quote!(
+                *#place.lock().unwrap_or_else(::std::sync::PoisonError::into_inner) = #value;
+            ),
+            Setter::RwLock => quote!(
+                *#place.write().unwrap_or_else(::std::sync::PoisonError::into_inner) = #value;
+            ),
+        }
+    }
+}
+
+// `set_all(&self, v: T)` stores the value into every field through a shared reference, for
+// fields that are cells, atomics or locks built from the value
+fn expand_set_all(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        fill_type,
+        bounds,
+        param,
+        prelude,
+        ..
+    } = target;
+    // the value must go straight into the cell, and fields can't be set on their own when a
+    // constructor upholds invariants between them, or through the shared reference when they
+    // may be unaligned
+    let setter = Setter::from_type(fill_type).filter(|_| target.wrap.len() == 1);
+    let Some(setter) = setter else {
+        return quote!(::core::compile_error!(
+            "`set_all` needs the fields to be `Cell`s, `RefCell`s, atomics, `Mutex`es or `RwLock`s built from the value"
+        ););
+    };
+    if target.constructor.is_some() {
+        return quote!(::core::compile_error!(
+            "`set_all` can't set the fields on their own when the struct is built by a `constructor`"
+        ););
+    }
+    if target.packed {
+        return quote!(::core::compile_error!(
+            "`set_all` can't borrow the fields of a `#[repr(packed)]` struct"
+        ););
+    }
+
+    let value = target.param_value();
+    let mut sets = Vec::new();
+    for (member, field, attrs) in &target.fields {
+        if !is_splat_field(field, attrs) {
+            continue;
+        }
+        let set = if attrs.is_converted() || !attrs.wrap.is_empty() || attrs.clone_with.is_some() {
+            None
+        } else {
+            get_set(&field.ty, fill_type, quote!(self.#member), &setter, &value)
+        };
+        let Some(set) = set else {
+            return Error::new_spanned(
+                &field.ty,
+                "`set_all` can only set fields holding the value, or arrays and tuples of it",
+            )
+            .to_compile_error();
+        };
+        let cfgs = get_cfgs(field);
+        sets.push(quote!(#(#cfgs)* { #set }));
+    }
+    let doc = format!(
+        " Sets every field of the `{}` to the given `{}` through a shared reference.",
+        struct_name,
+        type_to_string(param_type),
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn set_all(&self, #param)
+        where
+            #(#bounds,)*
+        {
+            #prelude
+            #(#sets)*
+        }
+    )
+}
+
+// generates `visit` for every leaf of `place`, which is a leaf or an array or tuple of them,
+// borrowing arrays with `borrow` to loop over them, or gives up if it is something else
+fn for_each_leaf(
+    ty: &Type,
+    fill_type: &Type,
+    place: TokenStream2,
+    borrow: &TokenStream2,
+    visit: &dyn Fn(&TokenStream2) -> TokenStream2,
+) -> Option<TokenStream2> {
+    match ty {
+        _ if is_same_type(ty, fill_type) => Some(visit(&place)),
+        Type::Group(group) => for_each_leaf(&group.elem, fill_type, place, borrow, visit),
+        Type::Paren(paren) => for_each_leaf(&paren.elem, fill_type, place, borrow, visit),
+        Type::Array(array) => {
+            let body = for_each_leaf(&array.elem, fill_type, quote!((*elem)), borrow, visit)?;
+            Some(quote!(for elem in #borrow #place { #body }))
+        }
+        Type::Tuple(tuple) => {
+            let bodies = tuple
+                .elems
+                .iter()
+                .enumerate()
+                .map(|(index, elem)| {
+                    let index = Index::from(index);
+                    for_each_leaf(elem, fill_type, quote!(#place.#index), borrow, visit)
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(quote!(#(#bodies)*))
+        }
+        _ => None,
+    }
+}
+
+// counts the leaves of a field of type `ty` the way `get_fill` builds it, as a usize expression
+fn get_leaf_count(ty: &Type, fill_type: &Type) -> TokenStream2 {
+    match ty {
+        _ if is_same_type(ty, fill_type) => quote!(1),
+        // a length on its own can be a const generic parameter, which can't be computed with
+        Type::Array(array) if is_same_type(&array.elem, fill_type) => array.len.to_token_stream(),
+        Type::Array(array) => {
+            let elem_count = get_leaf_count(&array.elem, fill_type);
+            let len = &array.len;
+            quote!((#len) * #elem_count)
+        }
+        Type::Paren(paren) => get_leaf_count(&paren.elem, fill_type),
+        Type::Tuple(tuple) => {
+            let elem_counts = tuple
+                .elems
+                .iter()
+                .map(|elem| get_leaf_count(elem, fill_type));
+            quote!((0 #(+ #elem_counts)*))
+        }
+        _ => quote!(1),
+    }
+}
+
+// stores the value into every cell of `place`, which is a cell or an array or tuple of them,
+// or gives up if it is something else
+fn get_set(
+    ty: &Type,
+    fill_type: &Type,
+    place: TokenStream2,
+    setter: &Setter,
+    value: &Value,
+) -> Option<TokenStream2> {
+    for_each_leaf(ty, fill_type, place, &quote!(&), &|place| {
+        setter.set(place, &value.leaf(None))
+    })
+}
+
+// `update_all(&mut self, f: impl FnMut(&mut T))` calls `f` on every value in the fields
+fn expand_update_all(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        fill_type,
+        ..
+    } = target;
+    let updates = target.visit_leaves(true, &|place| quote!(f(&mut #place);));
+    let doc = format!(
+        " Calls `f` with a mutable reference to every `{}` in the fields of the `{}`.",
+        type_to_string(fill_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn update_all(&mut self, mut f: impl ::core::ops::FnMut(&mut #fill_type)) {
+            #(#updates)*
+        }
+    )
+}
+
+// gets the type of `first`, an `Option` holding the first value in the fields, and the
+// statements setting it and comparing every later value to it, which evaluate `mismatch` when
+// one isn't equal, where the values are copied out of `#[repr(packed)]` structs since they
+// can't be borrowed
+fn get_equal_checks(target: &Target, mismatch: TokenStream2) -> (TokenStream2, Vec<TokenStream2>) {
+    let fill_type = target.fill_type;
+    let first_type = if target.packed {
+        quote!(#fill_type)
+    } else {
+        quote!(&#fill_type)
+    };
+    let checks = target.visit_leaves(false, &|place| {
+        let value = if target.packed {
+            quote!(#place)
+        } else {
+            quote!(&#place)
+        };
+        quote!(
+            let value = #value;
+            match first {
+                ::core::option::Option::Some(first) if first != value => #mismatch,
+                ::core::option::Option::Some(_) => {}
+                ::core::option::Option::None => first = ::core::option::Option::Some(value),
+            }
+        )
+    });
+    (first_type, checks)
+}
+
+// `scale_all(&mut self, v: T)` and `add_all(&mut self, v: T)` multiply or offset every value
+// in the fields by `v` in place, with the method of the given assignment operator trait, where
+// `verb` starts the doc
+fn expand_assign_all(
+    target: &Target,
+    method_name: &str,
+    op_trait: TokenStream2,
+    op_method: TokenStream2,
+    verb: &str,
+) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        fill_type,
+        ..
+    } = target;
+    let method_name = format_ident!("{}", method_name);
+    let clone_bound = target.bound_on(fill_type, quote!(::core::clone::Clone));
+    let op_bound = target.bound_on(fill_type, quote!(::core::ops::#op_trait));
+    let updates = target.visit_leaves(true, &|place| {
+        quote!(
+            ::core::ops::#op_trait::#op_method(&mut #place, ::core::clone::Clone::clone(&v));
+        )
+    });
+    let doc = format!(
+        " {} every `{}` in the fields of the `{}` by `v`.",
+        verb,
+        type_to_string(fill_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn #method_name(&mut self, v: #fill_type)
+        where
+            #clone_bound,
+            #op_bound,
+        {
+            #(#updates)*
+        }
+    )
+}
+
+// gets an iterator over references to every leaf of `place`, which is a leaf or an array or
+// tuple of them, borrowing them mutably or not, or gives up if it is something else
+fn get_leaf_iter(
+    ty: &Type,
+    fill_type: &Type,
+    place: TokenStream2,
+    borrow: &TokenStream2,
+) -> Option<TokenStream2> {
+    match ty {
+        _ if is_same_type(ty, fill_type) => Some(quote!(::core::iter::once(#borrow #place))),
+        Type::Group(group) => get_leaf_iter(&group.elem, fill_type, place, borrow),
+        Type::Paren(paren) => get_leaf_iter(&paren.elem, fill_type, place, borrow),
+        Type::Array(array) if is_same_type(&array.elem, fill_type) => Some(quote!(
+            ::core::iter::IntoIterator::into_iter(#borrow #place)
+        )),
+        Type::Array(array) => {
+            let elem_iter = get_leaf_iter(&array.elem, fill_type, quote!((*elem)), borrow)?;
+            Some(quote!(
+                ::core::iter::Iterator::flat_map(
+                    ::core::iter::IntoIterator::into_iter(#borrow #place),
+                    |elem| #elem_iter,
+                )
+            ))
+        }
+        Type::Tuple(tuple) => {
+            let elem_iters = tuple
+                .elems
+                .iter()
+                .enumerate()
+                .map(|(index, elem)| {
+                    let index = Index::from(index);
+                    get_leaf_iter(elem, fill_type, quote!(#place.#index), borrow)
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(quote!(
+                ::core::iter::empty()#(.chain(#elem_iters))*
+            ))
+        }
+        _ => None,
+    }
+}
+
+// checks whether the length of an array in `ty` mentions the struct's generic parameters
+fn has_generic_len(ty: &Type, generics: &Generics) -> bool {
+    match ty {
+        Type::Array(array) => {
+            let len = &array.len;
+            let len: Type = parse_quote!([(); #len]);
+            references_generics(&len, generics) || has_generic_len(&array.elem, generics)
+        }
+        Type::Group(group) => has_generic_len(&group.elem, generics),
+        Type::Paren(paren) => has_generic_len(&paren.elem, generics),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .any(|elem| has_generic_len(elem, generics)),
+        _ => false,
+    }
+}
+
+// `to_ref_array(&self) -> [&T; N]` and `to_mut_array(&mut self) -> [&mut T; N]` borrow every
+// value in the fields as an array, which `#[repr(packed)]` structs can't lend out
+fn expand_to_array(target: &Target, mutable: bool) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        fill_type,
+        ..
+    } = target;
+    let (method_name, receiver, borrow) = if mutable {
+        (quote!(to_mut_array), quote!(&mut self), quote!(&mut))
+    } else {
+        (quote!(to_ref_array), quote!(&self), quote!(&))
+    };
+    if target.packed {
+        let message = format!(
+            "`{}` can't borrow the fields of a `#[repr(packed)]` struct",
+            method_name
+        );
+        return quote!(::core::compile_error!(#message););
+    }
+
+    let len = match target.visited_leaf_count() {
+        Ok(len) => len,
+        Err(error) => return error.to_compile_error(),
+    };
+    let chains = target
+        .fields
+        .iter()
+        .filter(|(_, field, attrs)| {
+            is_splat_field(field, attrs) && !attrs.is_converted() && attrs.wrap.is_empty()
+        })
+        .filter_map(|(member, field, _)| {
+            let cfgs = get_cfgs(field);
+            let iter = get_leaf_iter(&field.ty, fill_type, quote!(self.#member), &borrow)?;
+            Some(quote!(
+                #(#cfgs)*
+                let leaves = ::core::iter::Iterator::chain(leaves, #iter);
+            ))
+        });
+    let next = quote!(match ::core::iter::Iterator::next(&mut leaves) {
+        ::core::option::Option::Some(leaf) => leaf,
+        ::core::option::Option::None => ::core::unreachable!(),
+    });
+    // `array::from_fn` needs Rust 1.63, like the arrays built by the splat
+    let build = if target.array_map {
+        quote!([(); #len].map(|()| #next))
+    } else {
+        quote!(::core::array::from_fn(|_| #next))
+    };
+    let doc = format!(
+        " Returns {} to every `{}` in the fields of the `{}`, in declaration order.",
+        if mutable {
+            "mutable references"
+        } else {
+            "references"
+        },
+        type_to_string(fill_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn #method_name(#receiver) -> [#borrow #fill_type; #len] {
+            let leaves = ::core::iter::empty::<#borrow #fill_type>();
+            #(#chains)*
+            let mut leaves = leaves;
+            #build
+        }
+    )
+}
+
+// `is_splat(&self) -> bool` checks whether every value in the fields is equal
+fn expand_is_splat(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        fill_type,
+        ..
+    } = target;
+    let bound = target.bound_on(fill_type, quote!(::core::cmp::PartialEq));
+    let (first_type, checks) = get_equal_checks(target, quote!(return false));
+    let doc = format!(
+        " Returns whether every `{}` in the fields of the `{}` is equal.",
+        type_to_string(fill_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn is_splat(&self) -> bool
+        where
+            #bound,
+        {
+            let mut first = ::core::option::Option::<#first_type>::None;
+            #(#checks)*
+            true
+        }
+    )
+}
+
+// `splat_value(&self) -> Option<&T>` gets the value every field holds, if they are all equal,
+// which is copied out of `#[repr(packed)]` structs, making it return an `Option<T>`
+fn expand_splat_value(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        fill_type,
+        ..
+    } = target;
+    let bound = target.bound_on(fill_type, quote!(::core::cmp::PartialEq));
+    let (first_type, checks) =
+        get_equal_checks(target, quote!(return ::core::option::Option::None));
+    let doc = format!(
+        " Returns the `{}` every field of the `{}` holds, or `None` if they aren't all equal.",
+        type_to_string(fill_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_value(&self) -> ::core::option::Option<#first_type>
+        where
+            #bound,
+        {
+            let mut first = ::core::option::Option::<#first_type>::None;
+            #(#checks)*
+            first
+        }
+    )
+}
+
+// `eq_splat(&self, v: &T) -> bool` checks whether every value in the fields is equal to `v`
+fn expand_eq_splat(target: &Target) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        fill_type,
+        ..
+    } = target;
+    let bound = target.bound_on(fill_type, quote!(::core::cmp::PartialEq));
+    let checks = target.visit_leaves(false, &|place| {
+        quote!(
+            if #place != *v {
+                return false;
+            }
+        )
+    });
+    let doc = format!(
+        " Returns whether every `{}` in the fields of the `{}` is equal to `v`.",
+        type_to_string(fill_type),
+        struct_name,
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn eq_splat(&self, v: &#fill_type) -> bool
+        where
+            #bound,
+        {
+            #(#checks)*
+            true
+        }
+    )
+}
+
+// `const NAME: Self`, generated for each constant given by `const_value`, which copies the
+// value into every field like `const_fn`, so it is available in const contexts
+pub(crate) fn expand_const_value(target: &Target, const_value: &ConstValue) -> TokenStream2 {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        ..
+    } = target;
+    let ConstValue { name, value, text } = const_value;
+    let construct = target.construct(&Value::Copied(quote!(v)));
+    let doc = format!(" A `{}` with every field set to `{}`.", struct_name, text);
+
+    quote!(
+        #[doc = #doc]
+        #vis const #name: Self = {
+            let v: #param_type = #value;
+            #construct
+        };
+    )
+}
+
+// implements `trait_path` for the struct with the given items, under the bounds of the splat and
+// `bound`, if there is one
+fn expand_trait_impl(
+    target: &Target,
+    trait_path: TokenStream2,
+    bound: Option<TokenStream2>,
+    allow_lints: &TokenStream2,
+    items: TokenStream2,
+) -> TokenStream2 {
+    let Target {
+        struct_name,
+        generics,
+        bounds,
+        ..
+    } = target;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let predicates = where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates);
+
+    quote!(
+        #[automatically_derived]
+        #allow_lints
+        impl #impl_generics #trait_path for #struct_name #ty_generics
+        where
+            #(#predicates,)*
+            #(#bounds,)*
+            #bound
+        {
+            #items
+        }
+    )
+}
+
+// `impl Default`, generated by `impl_default`, which splats the given expression or the value's
+// own default
+pub(crate) fn expand_impl_default(
+    target: &Target,
+    value: Option<&Expr>,
+    allow_lints: &TokenStream2,
+) -> TokenStream2 {
+    let Target {
+        param_type,
+        range_check,
+        ..
+    } = target;
+    let (value, default_bound) = match value {
+        Some(value) => (quote!(#value), None),
+        None => (
+            quote!(<#param_type as ::core::default::Default>::default()),
+            Some(target.bound(quote!(::core::default::Default))),
+        ),
+    };
+    let construct = target.construct(&target.owned_value());
+
+    expand_trait_impl(
+        target,
+        quote!(::core::default::Default),
+        default_bound,
+        allow_lints,
+        quote!(
+            #[inline]
+            fn default() -> Self {
+                let v: #param_type = #value;
+                #range_check
+                #construct
+            }
+        ),
+    )
+}
+
+// `impl From<T>`, generated by `impl_from`, which splats the value it is converted from like
+// `splat`, though it always takes the value itself, whatever `splat` takes
+pub(crate) fn expand_impl_from(target: &Target, allow_lints: &TokenStream2) -> TokenStream2 {
+    let Target {
+        param_type,
+        range_check,
+        ..
+    } = target;
+    let construct = target.construct(&target.owned_value());
+
+    expand_trait_impl(
+        target,
+        quote!(::core::convert::From<#param_type>),
+        None,
+        allow_lints,
+        quote!(
+            #[inline]
+            fn from(v: #param_type) -> Self {
+                #range_check
+                #construct
+            }
+        ),
+    )
+}
+
+// `splat_initialized(v: T) -> Self` and `splat_empty() -> Self`, generated when `splat` takes
+// a `OnceCell<T>` or `OnceLock<T>`, fill every field with a cell holding the value or with an
+// empty cell
+pub(crate) fn expand_once_cell(target: &Target) -> Option<TokenStream2> {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        copy,
+        ..
+    } = target;
+    let value_type = get_once_cell_type(param_type)?;
+    let (value, bound) = if *copy {
+        (
+            quote!(v),
+            target.bound_on(value_type, quote!(::core::marker::Copy)),
+        )
+    } else {
+        (
+            quote!(::core::clone::Clone::clone(&v)),
+            target.bound_on(value_type, quote!(::core::clone::Clone)),
+        )
+    };
+    let construct_initialized = target.construct(&Value::Fresh(quote!(
+        <#param_type as ::core::convert::From<#value_type>>::from(#value)
+    )));
+    let construct_empty = target.construct(&Value::Fresh(quote!(<#param_type>::new())));
+    let initialized_doc = format!(
+        " Creates a `{}` with every cell initialized to the given `{}`.",
+        struct_name,
+        type_to_string(value_type),
+    );
+    let empty_doc = format!(
+        " Creates a `{}` with every cell left uninitialized.",
+        struct_name
+    );
+
+    Some(quote!(
+        #[doc = #initialized_doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_initialized(v: #value_type) -> Self
+        where
+            #bound
+        {
+            #construct_initialized
+        }
+
+        #[doc = #empty_doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_empty() -> Self {
+            #construct_empty
+        }
+    ))
+}
+
+// gets `T` from `OnceCell<T>` or `OnceLock<T>`
+fn get_once_cell_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if type_path.qself.is_some() || (segment.ident != "OnceCell" && segment.ident != "OnceLock") {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(ty) if arguments.args.len() == 1 => Some(ty),
+        _ => None,
+    }
+}
+
+// `splat_n(v: T, len: usize) -> Self`, generated when `splat` takes a `Vec<T>` or `Box<[T]>`,
+// fills every field with a collection of `len` clones of the value
+pub(crate) fn expand_collection(target: &Target) -> Option<TokenStream2> {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        copy,
+        ..
+    } = target;
+    let elem_type = get_collection_elem_type(param_type)?;
+    let (elem, bound) = if *copy {
+        (
+            quote!(v),
+            target.bound_on(elem_type, quote!(::core::marker::Copy)),
+        )
+    } else {
+        (
+            quote!(::core::clone::Clone::clone(&v)),
+            target.bound_on(elem_type, quote!(::core::clone::Clone)),
+        )
+    };
+    // collecting the elements builds both kinds of collection without naming `alloc`
+    let construct = target.construct(&Value::Fresh(quote!(
+        <#param_type as ::core::iter::FromIterator<#elem_type>>::from_iter(
+            ::core::iter::repeat(#elem).take(len),
+        )
+    )));
+    let doc = format!(
+        " Creates a `{}` with every field set to `len` clones of the given `{}`.",
+        struct_name,
+        type_to_string(elem_type),
+    );
+
+    Some(quote!(
+        #[doc = #doc]
+        #[inline]
+        #[must_use]
+        #vis fn splat_n(v: #elem_type, len: usize) -> Self
+        where
+            #bound
+        {
+            #construct
+        }
+    ))
+}
+
+// gets `T` from `Vec<T>` or `Box<[T]>`
+fn get_collection_elem_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    if type_path.qself.is_some() || arguments.args.len() != 1 {
+        return None;
+    }
+    match arguments.args.first()? {
+        GenericArgument::Type(elem) if segment.ident == "Vec" => Some(elem),
+        GenericArgument::Type(Type::Slice(slice)) if segment.ident == "Box" => Some(&slice.elem),
+        _ => None,
+    }
+}
+
+// `try_splat(v: u32) -> Option<Self>`, generated when `splat` takes a `NonZeroU32` or another
+// non-zero integer, checks the value once and fills every field with it if it isn't zero
+pub(crate) fn expand_non_zero(target: &Target, method_name: &Ident) -> Option<TokenStream2> {
+    let Target {
+        struct_name,
+        vis,
+        param_type,
+        range_check,
+        ..
+    } = target;
+    let integer_type = get_non_zero_type(param_type)?;
+    let try_method_name = format_ident!("try_{}", method_name);
+    // the value is built here, so it is owned even when `splat` takes a reference
+    let construct = target.construct(&target.owned_value());
+    let doc = format!(
+        " Creates a `{}` with every field set to the given `{}`, or returns `None` if it is zero.",
+        struct_name,
+        type_to_string(&integer_type),
+    );
+
+    Some(quote!(
+        #[doc = #doc]
+        #[inline]
+        #vis fn #try_method_name(v: #integer_type) -> ::core::option::Option<Self> {
+            let v = <#param_type>::new(v)?;
+            #range_check
+            ::core::option::Option::Some(#construct)
+        }
+    ))
+}
+
+// gets the integer type of a non-zero integer, like `u32` for `NonZeroU32` or `NonZero<u32>`
+fn get_non_zero_type(ty: &Type) -> Option<Type> {
+    const INTEGERS: &[&str] = &[
+        "I8", "I16", "I32", "I64", "I128", "Isize", "U8", "U16", "U32", "U64", "U128", "Usize",
+    ];
+
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let name = segment.ident.to_string();
+    match &segment.arguments {
+        PathArguments::None => {
+            let integer = name.strip_prefix("NonZero")?;
+            INTEGERS.contains(&integer).then(|| {
+                let integer = format_ident!("{}", integer.to_lowercase());
+                parse_quote!(#integer)
+            })
+        }
+        PathArguments::AngleBracketed(arguments) if name == "NonZero" => {
+            match arguments.args.first()? {
+                GenericArgument::Type(ty) if arguments.args.len() == 1 => Some(ty.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
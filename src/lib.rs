@@ -8,12 +8,37 @@
 //! where each field is set to `v`.
 //!
 //! This crate provides a macro that generates a `splat` method for any struct that
-//! has fields which are all of the same type. However, the type shared by each field 
+//! has fields which are all of the same type. However, the type shared by each field
 //! must implement [Clone].
+//!
+//! # Features
+//!
+//! - `nightly-diagnostics`: reports errors through the unstable `proc_macro::Diagnostic`
+//!   API, so notes and help are attached to errors the way rustc's own are. Requires a
+//!   nightly compiler.
+//! - `verbose-errors`: appends a longer explanation of the rule that was broken to each error,
+//!   which can help when getting started with the macro.
+//! - `bumpalo`: allows the `splat_in` extra method, which builds the struct in a
+//!   `bumpalo::Bump` arena.
+
+#![cfg_attr(feature = "nightly-diagnostics", feature(proc_macro_diagnostic))]
 
+mod attr;
+mod diagnostic;
+mod extra;
+
+use attr::{ContainerAttrs, FieldAttrs, MISMATCH_WORKAROUNDS};
+use diagnostic::{
+    Message, EXPLAIN_NO_FIELDS, EXPLAIN_RECURSIVE, EXPLAIN_SAME_TYPE, EXPLAIN_STRUCTS_ONLY,
+};
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, punctuated::Iter, Data, DeriveInput, Field, Fields, Type};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Data,
+    DeriveInput, Error, Expr, Field, Fields, GenericArgument, GenericParam, Generics, Ident, Index,
+    Member, Meta, Path, PathArguments, PathSegment, Result, Token, Type, Visibility,
+};
 
 /// Derive macro generating a `splat` method for the struct
 ///
@@ -21,9 +46,22 @@ use syn::{parse_macro_input, punctuated::Iter, Data, DeriveInput, Field, Fields,
 ///
 /// - The data structure is a struct (not an enum)
 /// - The struct has at least one field
-/// - Every field in the struct is of the same type
+/// - Every field in the struct is of the same type, or is a tuple or array built only from
+///   the same type, apart from any `PhantomData` fields
 /// - The type shared by each field implements [Clone]
 ///
+/// Generic parameters of the struct, including lifetimes, are carried over to the
+/// generated impl, so structs like `Foo<'a, T> { a: &'a T, b: &'a T }` work as expected.
+/// Only the shared field type is required to implement [Clone]; other parameters, such as
+/// markers used by `PhantomData` fields, are simply passed through. The [Clone] bound is
+/// placed on the `splat` method itself, so the struct stays usable with non-[Clone] types.
+///
+/// The generated method comes with a doc comment naming the struct and the type it takes, so
+/// it reads well in rustdoc next to hand-written methods.
+///
+/// Types are compared as written, since the macro runs before types are resolved. Type macros
+/// such as `my_ty!()` are compared by their tokens, so each field must use the same invocation.
+///
 /// # Examples
 ///
 /// ## Struct
@@ -82,66 +120,1715 @@ use syn::{parse_macro_input, punctuated::Iter, Data, DeriveInput, Field, Fields,
 ///     assert_eq!(foo.1, -5);
 /// }
 /// ```
-
-#[proc_macro_derive(Splat)]
+///
+/// ## Generic Struct
+/// ```
+/// use splat_derive::Splat;
+///
+/// // macro used here
+/// #[derive(Splat)]
+/// struct Foo<T> {
+///     field_one: T,
+///     field_two: T,
+/// }
+///
+/// // generated code
+/// /*
+/// impl<T> Foo<T> {
+///     fn splat(v: T) -> Self
+///     where
+///         T: Clone,
+///     {
+///         Foo {
+///             field_one: v.clone(),
+///             field_two: v.clone(),
+///         }
+///     }
+/// }
+/// */
+///
+/// fn bar() {
+///     let foo = Foo::splat(String::from("baz"));
+///     assert_eq!(foo.field_one, "baz");
+///     assert_eq!(foo.field_two, "baz");
+/// }
+/// ```
+///
+/// ## Composite Fields
+///
+/// When the fields differ, but each one is a tuple or array built only from the same type,
+/// `splat` takes that type and fills every leaf.
+/// ```
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// struct Foo {
+///     single: u8,
+///     pair: (u8, u8),
+///     nested: ((u8, u8), [u8; 3]),
+/// }
+///
+/// fn bar() {
+///     let foo = Foo::splat(1);
+///     assert_eq!(foo.single, 1);
+///     assert_eq!(foo.pair, (1, 1));
+///     assert_eq!(foo.nested, ((1, 1), [1, 1, 1]));
+/// }
+/// ```
+///
+/// ## Const Generic Arrays
+///
+/// When every field is an array sized by a const generic parameter, `splat` takes the
+/// element type and fills every element of every field.
+/// ```
+/// use splat_derive::Splat;
+///
+/// // macro used here
+/// #[derive(Splat)]
+/// struct Buf<const N: usize> {
+///     data: [f32; N],
+///     shadow: [f32; N],
+/// }
+///
+/// // generated code
+/// /*
+/// impl<const N: usize> Buf<N> {
+///     fn splat(v: f32) -> Self {
+///         Buf {
+///             data: core::array::from_fn(|_| v.clone()),
+///             shadow: core::array::from_fn(|_| v.clone()),
+///         }
+///     }
+/// }
+/// */
+///
+/// fn bar() {
+///     let buf = Buf::<3>::splat(1.0);
+///     assert_eq!(buf.data, [1.0; 3]);
+///     assert_eq!(buf.shadow, [1.0; 3]);
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// When one of the conditions above isn't met, the macro emits a compile error rather
+/// than panicking, so it is reported like any other rustc diagnostic.
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// enum Foo {
+///     Bar,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// struct Foo {
+///     field_one: u8,
+///     field_two: u16,
+/// }
+/// ```
+///
+/// A shared type that doesn't implement [Clone] is reported at the type itself.
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// struct NotClone;
+///
+/// #[derive(Splat)]
+/// struct Foo {
+///     field_one: NotClone,
+///     field_two: NotClone,
+/// }
+/// ```
+///
+/// Options passed to `#[splat(...)]` are validated, and unknown or misplaced options are
+/// reported at the option itself.
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// #[splat(unknown)]
+/// struct Foo {
+///     field_one: u8,
+///     field_two: u8,
+/// }
+/// ```
+///
+/// Options that contradict each other are rejected too, like a field's `clone_with` under a
+/// struct's `copy`, which already says how each field gets its value.
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// #[splat(copy)]
+/// struct Foo {
+///     #[splat(clone_with = "Clone::clone")]
+///     field_one: u8,
+///     field_two: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// #[splat(const_fn)]
+/// struct Foo {
+///     #[splat(clone_with = "Clone::clone")]
+///     field_one: u8,
+///     field_two: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// #[splat(range = "0..=10", impl_from)]
+/// struct Foo {
+///     field_one: u8,
+///     field_two: u8,
+/// }
+/// ```
+///
+/// Recursive structs are rejected, since `splat` would need an instance of the struct to
+/// construct one.
+/// ```compile_fail
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// struct Node {
+///     left: Box<Self>,
+///     right: Box<Self>,
+/// }
+/// ```
+///
+/// # Options
+///
+/// Options are passed through `#[splat(...)]` attributes, either on the struct or on its fields.
+///
+/// ## Struct Options
+///
+/// - `allow_single_field`: silences the warning emitted for structs with a single field of the
+///   type `splat` takes, whose `splat` is the same as constructing the struct directly. Single
+///   fields filled element by element, like `[f32; N]` arrays, aren't warned about.
+/// - `rename = "name"`: generates the method under the given name instead of `splat`, e.g.
+///   when the struct already has a `splat` method.
+/// - `alias = "name, ..."`: also generates methods under each of the given names, which
+///   delegate to `splat`, e.g. `alias = "broadcast"` to provide both `splat` and `broadcast`.
+/// - `wrap = "Arc::new"`: takes the type wrapped by the fields' type, like `T` for `Arc<T>`
+///   fields, and passes each field's value to the given function, so
+///   `struct Shared { a: Arc<Config>, b: Arc<Config> }` gets `splat(v: Config)`. Several
+///   functions can be chained, outermost first, like `wrap = "Arc::new, Mutex::new"` for
+///   `Arc<Mutex<T>>` fields. Fields of `Box`, `Cell`, `RefCell`, `Mutex` and `RwLock`, or any
+///   nesting of them, as well as atomics like `AtomicBool`, which take the value they hold,
+///   are wrapped like this automatically, which `wrap = ""` turns off so that `splat` takes
+///   the wrapper itself. `Rc` and `Arc` fields aren't, so they share the pointer given to
+///   `splat`, unless `wrap` asks for a new one in each field.
+/// - `deep = "T"`: takes the given type and builds each field by calling the `splat` of the
+///   field's own type with it, e.g. `deep = "f32"` for a `Mat3` whose fields are `Vec3`s of
+///   `f32`s. The type has to be named, since a derive can't see the fields of other types.
+/// - `grouped`: accepts structs whose fields have different types, taking a parameter for
+///   each distinct type in the order the types first appear, which fills every field of that
+///   type, e.g. `splat(v0: u8, v1: f32)`. Options changing the parameter can't be combined
+///   with it.
+/// - `validate = "path", error = "E"`: also generates `try_splat`, which checks the struct
+///   with the given `fn(&Self) -> Result<(), E>` after splatting, and returns its error if the
+///   struct is invalid. `splat` itself is still generated, and doesn't check the struct.
+/// - `clamp = "0.0..=1.0"`: clamps the value into the given inclusive range before cloning it
+///   into each field. Either end of the range can be left out, like `clamp = "0..="`.
+/// - `range = "0.0..=1.0"`: makes `splat` panic when the value is outside of the given
+///   inclusive range, and also generates `try_splat`, which returns the value back as the
+///   error instead.
+/// - `msrv = "1.56"`: restricts the generated code to what the given Rust version supports,
+///   e.g. building arrays by mapping an array of units rather than with `array::from_fn`
+///   before 1.63. Versions before 1.56 aren't supported.
+/// - `debug_expand`: reports the generated code as a warning on the struct, to see what was
+///   generated without installing `cargo expand`. The code is shown as tokens, so it is only
+///   roughly formatted.
+/// - `impl_trait = "path::Trait"`: generates the method in an impl of the given trait rather
+///   than as an inherent method, so it can be used in generic code. The trait must declare a
+///   `fn splat(v: T) -> Self` taking the type `splat` would take, and its generic arguments
+///   can be given, like `impl_trait = "UniformInit<f32>"`.
+/// - `crate = "path"`: reaches the crates the generated code uses outside of `core` and `std`
+///   through the given path, like serde's option of the same name, for when `Splat` is used
+///   through a facade crate re-exporting them. `splat_in` then names `path::bumpalo::Bump`
+///   instead of `::bumpalo::Bump`, so only the facade crate needs `bumpalo` as a dependency.
+/// - `constructor = "new"`: builds the struct by calling the given associated function with
+///   a value for each field in order, instead of writing out the struct, so a constructor
+///   upholding the struct's invariants is still used. `PhantomData` fields aren't passed.
+/// - `vis = "pub(crate)"`: sets the visibility of the generated method, which matches the
+///   struct's visibility by default. Any visibility can be given, and `vis = ""` makes the
+///   method private.
+/// - `const_fn`: generates a `const fn`, which copies the value into each field instead of
+///   cloning it, so the shared type must implement [Copy].
+/// - `copy`: copies the value into each field instead of cloning it, so the shared type must
+///   implement [Copy], and no `clone` calls are left for debug builds to run.
+/// - `bound = "T: Trait"`: replaces the automatic `T: Clone` bound on the generated methods
+///   with the given where predicates. The automatic bound isn't kept, so include it when only
+///   adding to it, and use `bound = ""` to remove it.
+/// - `cfg(predicate)`: only generates the methods when the given `cfg` predicate holds, e.g.
+///   `#[splat(cfg(test))]` to only generate them for tests.
+/// - `extra(method, ...)`: also generates each of the listed methods, see
+///   [Extra Methods](#extra-methods).
+/// - `const_value(NAME = "expr", ...)`: also generates an associated constant `NAME: Self`
+///   for each of the given names, with every field set to the expression, so uniform values
+///   like `ZEROED` can be used in const contexts. Like `const_fn`, the value is copied into
+///   each field, so the shared type has to be Copy, and the expression has to be a constant.
+/// - `impl_default` or `impl_default = "expr"`: implements [Default] for the struct by setting
+///   every field to the shared type's own default, or to the given expression, instead of
+///   deriving it and filling the fields separately.
+/// - `impl_from`: implements `From<T>` for the struct by splatting the value, so it can be
+///   built with `.into()` and by generic code taking `impl Into<Self>`. It takes the shared
+///   type itself, even when `splat` takes something else through `input`, `into` or `by_ref`.
+///   It can't be combined with `range`, since `From` can't fail.
+/// - `doc_hidden`: hides the generated method from rustdoc with `#[doc(hidden)]`.
+/// - `doc = "text"`: replaces the generated method's documentation with the given text.
+/// - `doc_alias = "name, ..."`: adds `#[doc(alias = "name")]` to the generated method for each
+///   of the given names, so rustdoc's search also finds it under them.
+/// - `into`: takes `impl Into<T>` instead of the shared type `T`, converting the value once
+///   before cloning it into each field.
+/// - `try_into`: also generates `try_splat`, which takes any value implementing
+///   `TryInto<T>` and returns the conversion's error if it fails.
+/// - `input = "U"`: takes a `U` instead of the shared type `T`, converting it into `T` once
+///   with [Into] before cloning it into each field, e.g. to take a `u64` for `u128` fields.
+/// - `by_ref`: takes `&T` instead of `T`, cloning each field's value from the reference.
+///
+/// ## Field Options
+///
+/// - `skip`: leaves the field out of the splat, filling it with `Default::default()` instead,
+///   so its type doesn't need to match the other fields.
+/// - `with = "path"`: fills the field with the result of the given `fn(T) -> U`, which is
+///   passed a clone of the value, so the field's type doesn't need to match the other fields.
+/// - `with_ref = "path"`: fills the field like `with`, but passes a reference to the value to
+///   the given `fn(&T) -> U`, without cloning it.
+/// - `nested`: fills the field by passing the value to the `splat` of the field's type, so
+///   structs deriving `Splat` can be nested inside each other.
+/// - `cast`: fills the field by converting the value to its type with `as`, so numeric fields
+///   of different widths can be filled by the same value.
+/// - `checked_cast`: fills the field like `cast`, but converts the value with `TryFrom` and
+///   panics if it doesn't fit in the field's type.
+/// - `into`: fills the field by converting the value to its type with [From], so fields of
+///   any type implementing `From<T>` can be filled.
+/// - `wrap = "Box::new"`: passes the field's value to the given functions, outermost first,
+///   so the field has the type they return, like `Box<T>`, and fields can use different
+///   wrappers. This is applied outside of the struct's own `wrap`.
+/// - `share`: clones the handle of an `Rc` or `Arc` field with `Rc::clone`, so the field
+///   shares the pointer given to `splat` rather than anything being deep copied.
+/// - `clone_with = "path"`: duplicates the value for the field with the given `fn(&T) -> T`,
+///   like `Rc::clone`, instead of `Clone::clone`.
+/// - `skip_with = "expr"`: leaves the field out of the splat like `skip`, filling it with the
+///   given expression instead, for fields without a sensible default.
+///
+/// Fields with `#[cfg(...)]` attributes are only filled when they are compiled in, and options
+/// can be given conditionally with `#[cfg_attr(..., splat(...))]`, on fields or the struct.
+/// ```
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// struct Foo {
+///     bar: f32,
+///     baz: f32,
+///     #[splat(skip)]
+///     id: u64,
+///     #[splat(skip_with = "String::from(\"foo\")")]
+///     name: String,
+/// }
+///
+/// fn qux() {
+///     let foo = Foo::splat(1.0);
+///     assert_eq!(foo.bar, 1.0);
+///     assert_eq!(foo.id, 0);
+///     assert_eq!(foo.name, "foo");
+/// }
+/// ```
+///
+/// ## Extra Methods
+///
+/// These methods are only generated when they are listed in `#[splat(extra(...))]`, where
+/// `T` is the type the fields are filled from, which `splat` takes unless `input`, `into` or
+/// `by_ref` change its parameter. The methods working on the fields of an existing instance,
+/// `update_all`, `scale_all`, `add_all`, `to_ref_array`, `to_mut_array`, `is_splat`,
+/// `splat_value` and `eq_splat`, use the type the fields hold instead, which is the wrapper
+/// for wrapped fields, like `Box<T>` for `Box` fields.
+///
+/// - `fill(&mut self, v: T)`: sets every field of an existing instance to a clone of `v`. It
+///   takes the same parameter as `splat`, which is converted, clamped or checked the same way,
+///   and fields holding the value itself are cloned into with [Clone::clone_from], so their
+///   allocations are reused.
+/// - `splat_with(f: impl FnMut() -> T) -> Self`: sets every field to a value returned by `f`,
+///   which is called once per field, so `T` doesn't need to implement [Clone]. The struct
+///   still derives when it doesn't, and only the methods cloning the value can't be used.
+/// - `splat_with_index(f: impl FnMut(usize) -> T) -> Self`: sets every field to the value
+///   returned by `f` for the field's index in the declaration, counting from 0.
+/// - `splat_with_name(f: impl FnMut(&'static str) -> T) -> Self`: sets every field to the
+///   value returned by `f` for the field's name, or its index for tuple structs.
+/// - `try_splat_with(f: impl FnMut() -> Result<T, E>) -> Result<Self, E>`: sets every field to
+///   a value returned by `f` like `splat_with`, stopping at the first error. With array fields,
+///   every value is taken from `f` before the struct is built, which needs `std`.
+/// - `splat_default() -> Self`: sets every field to `T::default()`, which is created once per
+///   field, so like `splat_with` it doesn't need `T` to implement [Clone].
+/// - `update_all(&mut self, f: impl FnMut(&mut T))`: calls `f` on every value in the fields,
+///   including each element of array and tuple fields, to scale or reset them in place.
+/// - `set_all(&self, v: T)`: stores a clone of `v` into every field through a shared
+///   reference, for fields that are `Cell`s, `RefCell`s, atomics, `Mutex`es or `RwLock`s
+///   built from `T`, or arrays and tuples of them. It takes the same parameter as `splat`, so
+///   it follows `by_ref`, `into`, `input` and `clamp`. Locks are expected to be the ones from
+///   `std`, whose poisoning is ignored since the whole value is replaced, and the method can't
+///   be generated for `#[repr(packed)]` structs, whose fields can't be borrowed.
+/// - `replace_all(&mut self, v: T) -> Self`: replaces the instance with `Self::splat(v)`,
+///   returning the previous one, like [core::mem::replace].
+/// - `with_all(self, v: T) -> Self`: returns the instance with every field set like `fill`,
+///   for builder-style call chains.
+/// - `splat_boxed(v: T) -> Box<Self>`: builds the struct directly on the heap, writing each
+///   field, and each element of array fields, into the allocation, so large structs aren't
+///   built on the stack first. This needs `std`, and the generated code uses `unsafe`.
+/// - `splat_uninit(dest: &mut MaybeUninit<Self>, v: T) -> &mut Self`: initializes
+///   already allocated memory in place, the same way as `splat_boxed`, and returns it as an
+///   initialized instance. Like `MaybeUninit::write`, a value already in `dest` is overwritten
+///   without being dropped.
+/// - `splat_in(arena: &Bump, v: T) -> &mut Self`: allocates the struct in a `bumpalo` arena
+///   and initializes it there in place, the same way as `splat_boxed`. This needs the
+///   `bumpalo` feature, and `bumpalo` as a dependency of the crate deriving `Splat`, or
+///   re-exported by the crate given by `crate`.
+/// - `splat_many(v: T, n: usize) -> Vec<Self>`: creates `n` splatted instances at once, without
+///   the struct needing to be [Clone]. This needs `std`.
+/// - `splat_seq(start: T, step: T) -> Self`: fills the fields in declaration order with
+///   `start`, `start + step`, `start + step + step` and so on, which needs `T: Add<Output = T>`.
+/// - `splat_gradient(start: T, end: T) -> Self`: fills the fields in declaration order with
+///   values evenly spaced from `start` to `end`, for floats or other types with arithmetic
+///   operators and `From<u16>`.
+/// - `splat_cycle(iter: impl IntoIterator<Item = T>) -> Self`: fills the fields in declaration
+///   order with the values of the iterator, starting over when it runs out, so `T` doesn't need
+///   to be [Clone], though the iterator does. Panics if the iterator is empty.
+/// - `copy_from_slice(&mut self, src: &[T])`: sets the fields in declaration order to the
+///   values of a slice of `Copy` values, panicking unless it holds exactly one for each of them,
+///   like the slice method. It comes with `try_copy_from_slice(&mut self, src: &[T]) ->
+///   Result<(), usize>`, which returns the number of values needed instead of panicking.
+/// - `try_splat_parse(s: &str) -> Result<Self, T::Err>`: parses the value from a string with
+///   `FromStr` once and sets every field to it, returning the error from parsing if it fails.
+/// - `is_splat(&self) -> bool`: checks whether every value in the fields is equal, which needs
+///   `T: PartialEq`.
+/// - `splat_value(&self) -> Option<&T>`: gets the value in the fields if they are all equal,
+///   to collapse the struct back into a single value. `#[repr(packed)]` structs can't lend out
+///   their fields, so for them it returns a copy, as an `Option<T>`.
+/// - `eq_splat(&self, v: &T) -> bool`: checks whether every value in the fields is equal to
+///   `v`, as the fields hold it. A struct from `Self::splat(v)` only compares equal to `v`
+///   when `splat` stores it unchanged, which isn't the case with `clamp`, and `v` has to be
+///   given as the fields hold it, such as a `Box<T>`, with `input`, `into` or wrapped fields.
+/// - `scale_all(&mut self, v: T)` and `add_all(&mut self, v: T)`: multiply or offset every
+///   value in the fields by `v` in place, which needs `T: MulAssign` or `T: AddAssign`.
+/// - `to_ref_array(&self) -> [&T; N]` and `to_mut_array(&mut self) -> [&mut T; N]`: borrow
+///   every value in the fields as an array, in declaration order, for looping over them or
+///   passing them to slice-based code. Unlike casting the struct to an array, this needs no
+///   `#[repr]`, but `#[repr(packed)]` structs can't lend out their fields at all. The length
+///   of the array can't depend on `#[cfg]` attributes on the fields, or on const generic
+///   parameters unless the struct is a single array.
+/// - `splat_then(v: T, f: impl FnOnce(&mut Self)) -> Self`: splats the value and passes the
+///   result to `f` to adjust before returning it, for structs that are uniform but for a few
+///   fields.
+/// ```
+/// use splat_derive::Splat;
+///
+/// #[derive(Splat)]
+/// #[splat(extra(fill, splat_with, splat_default))]
+/// struct Foo {
+///     bar: Vec<u8>,
+///     baz: Vec<u8>,
+/// }
+///
+/// fn qux() {
+///     let mut foo = Foo::splat_default();
+///     assert!(foo.bar.is_empty());
+///     foo.fill(vec![1]);
+///     assert_eq!(foo.baz, [1]);
+///     let foo = Foo::splat_with(|| Vec::with_capacity(8));
+///     assert!(foo.bar.capacity() >= 8);
+/// }
+/// ```
+///
+/// When `T` is a `Cow`, `splat` takes anything converting into it, like with `into`, so both a
+/// borrowed and an owned value can be passed.
+///
+/// Some methods are generated on their own, depending on the type taken by `splat`:
+///
+/// - `splat_some(v: U) -> Self` and `splat_none() -> Self`, when `T` is `Option<U>`, set
+///   every field to `Some` of a clone of `v`, or to `None`.
+/// - `splat_initialized(v: U) -> Self` and `splat_empty() -> Self`, when `T` is
+///   `OnceCell<U>` or `OnceLock<U>`, set every field to a cell holding a clone of `v`, or to
+///   an empty cell.
+/// - `splat_n(v: U, len: usize) -> Self`, when `T` is `Vec<U>` or `Box<[U]>`, sets every
+///   field to a collection of `len` clones of `v`.
+/// - `try_splat(v: u32) -> Option<Self>`, when `T` is a non-zero integer like `NonZeroU32`,
+///   checks `v` once and sets every field to it, or returns `None` if it is zero. It isn't
+///   generated when `try_into`, `validate` or `range` already generate a `try_splat`.
+#[proc_macro_derive(Splat, attributes(splat))]
 pub fn derive_splat(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
 
+    expand_splat(input).unwrap_or_else(diagnostic::emit).into()
+}
+
+// expands the derive, returning an error spanned to the offending tokens on failure
+fn expand_splat(input: DeriveInput) -> Result<TokenStream2> {
     // ensure that we are deriving a struct
     let data_struct = match input.data {
         Data::Struct(data_struct) => data_struct,
-        _ => panic!("Splat can only be derived by structs"),
+        Data::Enum(data_enum) => {
+            return Err(Error::new_spanned(
+                data_enum.enum_token,
+                Message::new("Splat can only be derived by structs").explain(EXPLAIN_STRUCTS_ONLY),
+            ))
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                Message::new("Splat can only be derived by structs").explain(EXPLAIN_STRUCTS_ONLY),
+            ))
+        }
     };
 
     // get the name of the struct we are deriving
     let struct_name = input.ident;
+    let generics = input.generics;
 
-    proc_macro::TokenStream::from(match data_struct.fields {
-        Fields::Named(fields_named) => {
-            let shared_type = get_shared_type(fields_named.named.iter());
-            let field_idents = fields_named.named.into_iter().map(|field| field.ident);
+    let fields = match data_struct.fields {
+        Fields::Named(fields_named) => fields_named.named,
+        Fields::Unnamed(fields_unnamed) => fields_unnamed.unnamed,
+        Fields::Unit => {
+            return Err(Error::new_spanned(
+                struct_name,
+                Message::new("Splat cannot be derived by unit structs").explain(EXPLAIN_NO_FIELDS),
+            ))
+        }
+    };
 
-            quote!(
-                impl #struct_name {
-                    pub fn splat(v: #shared_type) -> Self {
-                        Self {
-                            #(#field_idents: v.clone()),*
-                        }
-                    }
-                }
+    // parse every `#[splat(...)]` attribute, reporting all of their errors together
+    let (container_attrs, field_attrs) = attr::parse_attrs(&input.attrs, &fields)?;
+    let packed = is_packed(&input.attrs);
+
+    // PhantomData fields are filled with PhantomData and skipped fields on their own, so
+    // neither takes part in the splat
+    let splat_fields: Vec<&Field> = fields
+        .iter()
+        .zip(&field_attrs)
+        .filter(|(field, attrs)| is_splat_field(field, attrs))
+        .map(|(field, _)| field)
+        .collect();
+    if splat_fields.is_empty() {
+        return Err(Error::new_spanned(
+            struct_name,
+            Message::new("Splat cannot be derived by structs with no fields")
+                .explain(EXPLAIN_NO_FIELDS),
+        ));
+    }
+    // fields passing the value to a function don't need to have the type it takes, so the
+    // type is found from the others, looking inside the wrappers given by each field's `wrap`
+    let mut typed_fields = Vec::new();
+    let mut wrap_errors = Vec::new();
+    for (field, attrs) in fields.iter().zip(&field_attrs) {
+        if !is_splat_field(field, attrs) || attrs.is_converted() {
+            continue;
+        }
+        if attrs.share && !is_shared_pointer(&field.ty) {
+            wrap_errors.push(Error::new_spanned(
+                &field.ty,
+                Message::new("`share` can only be used on `Rc` and `Arc` fields")
+                    .note(format!("found `{}`", type_to_string(&field.ty))),
+            ));
+        }
+        match get_unwrapped_type(&field.ty, &attrs.wrap) {
+            Ok(ty) => typed_fields.push(Field {
+                ty: ty.clone(),
+                ..field.clone()
+            }),
+            Err(error) => wrap_errors.push(error),
+        }
+    }
+    combine_errors(wrap_errors)?;
+    if typed_fields.is_empty() && !container_attrs.grouped {
+        return Err(Error::new_spanned(
+            struct_name,
+            Message::new(
+                "Splat cannot find the type `splat` takes, since every field converts the value",
+            )
+            .help("leave `with` off of a field with the type `splat` should take"),
+        ));
+    }
+
+    // splatting a single field of the type `splat` takes is the same as constructing the
+    // struct, which usually means the derive was left behind by a refactor, while a single
+    // array or tuple field still has every element filled
+    let single_field_warning = |param_type: &Type| {
+        let single_field = match splat_fields.as_slice() {
+            [field] => is_same_type(&field.ty, param_type),
+            _ => false,
+        };
+        (single_field && !container_attrs.allow_single_field).then(|| {
+            diagnostic::warn(
+                struct_name.span(),
+                Message::new("Splat was derived by a struct with a single field, so `splat` is the same as constructing it directly")
+                    .help("remove the derive, or add `#[splat(allow_single_field)]` to the struct to keep it"),
             )
+        })
+    };
+
+    let method_name = container_attrs
+        .rename
+        .clone()
+        .unwrap_or_else(|| format_ident!("splat"));
+    // the method is as visible as the struct, unless the `vis` option says otherwise
+    let vis = container_attrs.vis.clone().unwrap_or(input.vis);
+
+    // with `grouped`, each distinct field type gets its own parameter instead of requiring
+    // the fields to share a type
+    if container_attrs.grouped {
+        // a single field is its own group, so its parameter has its type
+        let single_field_warning = single_field_warning(&splat_fields[0].ty);
+        let expansion = expand_grouped(
+            &struct_name,
+            &generics,
+            &fields,
+            &container_attrs,
+            &field_attrs,
+            &method_name,
+            &vis,
+        )?;
+        return Ok(finish_expansion(
+            quote!(#single_field_warning #expansion),
+            &struct_name,
+            &container_attrs,
+        ));
+    }
+
+    let shared_type = get_shared_type(&typed_fields.iter().collect::<Vec<_>>())?;
+    // the fields are filled with values of `fill_type`, which the functions given by `wrap`
+    // build from the parameter, outermost first
+    let fill_type = get_param_type(&shared_type, &generics);
+    // fields of common std wrappers, like `Box<T>`, are built from the wrapped type unless
+    // `wrap` is given, or the fields say how the wrapper itself is duplicated
+    let auto_wrap = container_attrs.deep.is_none()
+        && !container_attrs.const_fn
+        && field_attrs
+            .iter()
+            .all(|attrs| !attrs.share && attrs.clone_with.is_none());
+    let (wrap, auto_type) = match &container_attrs.wrap {
+        Some(wrap) => (wrap.clone(), None),
+        None if auto_wrap => {
+            let (wrap, auto_type) = get_auto_wrappers(fill_type);
+            (wrap, Some(auto_type))
         }
-        Fields::Unnamed(fields_unnamed) => {
-            let shared_type = get_shared_type(fields_unnamed.unnamed.iter());
-            let field_idents = fields_unnamed.unnamed.into_iter().map(|field| field.ident);
+        None => (Vec::new(), None),
+    };
+    let param_type = match (&container_attrs.deep, &auto_type) {
+        (Some(deep), _) => deep,
+        (None, Some(auto_type)) => auto_type,
+        (None, None) => get_unwrapped_type(fill_type, &wrap)?,
+    };
+    // with `deep`, each field is built by its own type's `splat`
+    let wrappers = match &container_attrs.deep {
+        Some(_) => vec![quote!(<#fill_type>::splat)],
+        None => wrap
+            .iter()
+            .map(|wrap| wrap.to_token_stream())
+            .collect::<Vec<_>>(),
+    };
+    if let Some(self_span) = find_self(param_type.to_token_stream()) {
+        return Err(Error::new(
+            self_span,
+            Message::new("Splat cannot be derived by recursive structs, since `splat` would need an instance of the struct to construct one")
+                .explain(EXPLAIN_RECURSIVE),
+        ));
+    }
+    let single_field_warning = single_field_warning(param_type);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // bounds on the generic parameters of const fns were only stabilized in 1.61
+    if let Some(msrv) = &container_attrs.msrv {
+        let has_type_params = generics
+            .params
+            .iter()
+            .any(|param| matches!(param, GenericParam::Type(_)));
+        if container_attrs.const_fn && has_type_params && msrv.version < (1, 61) {
+            return Err(Error::new(
+                msrv.span,
+                Message::new("`const_fn` needs Rust 1.61 for structs with generic types")
+                    .note("bounds on the generic parameters of const fns were stabilized in 1.61"),
+            ));
+        }
+    }
+
+    // `copy` and const fns copy the value into each field rather than cloning it
+    let copy = container_attrs.copy || container_attrs.const_fn;
+    let const_token = container_attrs.const_fn.then(|| quote!(const));
+
+    let (bound_assertion, bound) = get_bound(param_type, &generics, copy);
+    // when the struct can be built from fresh values, the type doesn't have to be Clone, so
+    // the bound is left to be checked wherever `splat` is used
+    let bound_assertion = bound_assertion.filter(|_| !container_attrs.has_fresh_value_method());
+
+    // the `bound` option replaces the automatic bound entirely, like serde's
+    let mut bounds = match &container_attrs.bound {
+        Some(predicates) => predicates.iter().map(|p| p.to_token_stream()).collect(),
+        None => vec![bound],
+    };
+
+    // `clamp` and `range` compare the value against the ends of their range
+    let value_range = container_attrs
+        .clamp
+        .as_ref()
+        .or(container_attrs.range.as_ref());
+    if value_range.is_some() && references_generics(param_type, &generics) {
+        bounds.push(quote!(#param_type: ::core::cmp::PartialOrd));
+    }
+
+    // with `input`, the method takes that type and converts it into the shared type, which
+    // needs a bound when either type is generic, and is checked in the body otherwise
+    let input_type = container_attrs.input.as_ref().unwrap_or(param_type);
+    if let Some(input) = &container_attrs.input {
+        if references_generics(input, &generics) || references_generics(param_type, &generics) {
+            bounds.push(quote!(#input: ::core::convert::Into<#param_type>));
+        }
+    }
+
+    // document the generated method, naming the struct and the type it takes, unless the
+    // documentation was replaced or hidden
+    let doc = if container_attrs.doc_hidden {
+        quote!(#[doc(hidden)])
+    } else if let Some(doc) = &container_attrs.doc {
+        quote!(#[doc = #doc])
+    } else {
+        let doc_summary = format!(
+            " Creates a `{}` with every field set to the given `{}`.",
+            struct_name,
+            type_to_string(param_type),
+        );
+        let wrapped = wrappers
+            .iter()
+            .rev()
+            .map(|wrap| format!(" passed to `{}`", wrap.to_string().replace(' ', "")))
+            .collect::<Vec<_>>()
+            .join(", then");
+        let doc_details = format!(
+            " Each field receives a {} of `v`{}, so `{}::{}(v)` is the same as writing out the struct with `v` in every field.",
+            if copy { "copy" } else { "clone" },
+            wrapped,
+            struct_name,
+            method_name,
+        );
+        quote!(
+            #[doc = #doc_summary]
+            #[doc = ""]
+            #[doc = #doc_details]
+        )
+    };
+
+    // `Cow` fields take anything converting into the `Cow`, so either a borrowed or an owned
+    // value can be passed, unless the parameter has to be written out as it is
+    let into = container_attrs.into
+        || (is_cow(param_type)
+            && container_attrs.input.is_none()
+            && !container_attrs.by_ref
+            && !container_attrs.const_fn
+            && container_attrs.impl_trait.is_none());
+
+    // with `input` or `into`, the value is converted once up front and then cloned into each
+    // field, and with `by_ref` it is cloned from the reference, or copied out of it up front
+    let (param, conversion) = if let Some(input) = &container_attrs.input {
+        (
+            quote!(v: #input),
+            Some(quote_spanned!(input.span()=>
+                let v: #param_type = ::core::convert::Into::into(v);
+            )),
+        )
+    } else if into {
+        (
+            quote!(v: impl ::core::convert::Into<#param_type>),
+            Some(quote!(let v: #param_type = ::core::convert::Into::into(v);)),
+        )
+    } else if container_attrs.by_ref && copy {
+        (
+            quote!(v: &#param_type),
+            Some(quote!(let v: #param_type = *v;)),
+        )
+    } else if container_attrs.by_ref {
+        (quote!(v: &#param_type), None)
+    } else {
+        (quote!(v: #param_type), None)
+    };
+    let splat_arg = if container_attrs.by_ref {
+        quote!(&v)
+    } else {
+        quote!(v)
+    };
+
+    // with `clamp`, the value is moved into the range once before it is cloned into each field,
+    // and with `range`, a value outside of it makes `splat` panic and `try_splat` fail
+    let range_check = if let Some(clamp) = &container_attrs.clamp {
+        let lower = clamp
+            .start
+            .iter()
+            .map(|start| quote!(if v < #start { #start }));
+        let upper = clamp.end.iter().map(|end| quote!(if v > #end { #end }));
+        Some(quote!(
+            let v: #param_type = #(#lower else)* #(#upper else)* { v };
+        ))
+    } else if let Some(range) = &container_attrs.range {
+        let in_range = range.in_range();
+        let message = format!(
+            "`{}::{}` was given a value outside of `{}`",
+            struct_name, method_name, range.text,
+        );
+        Some(quote!(::core::assert!(#in_range, #message);))
+    } else {
+        None
+    };
+
+    // the other methods call the generated one through the trait given by `impl_trait`, if any
+    let splat_path = match &container_attrs.impl_trait {
+        Some(impl_trait) => quote!(<Self as #impl_trait>::#method_name),
+        None => quote!(Self::#method_name),
+    };
+
+    // with `try_into`, a fallible method converts the value before splatting it
+    let try_method = container_attrs.try_into.then(|| {
+        let try_method_name = format_ident!("try_{}", method_name);
+        let input = get_fresh_param(&generics, "V");
+        let doc = format!(
+            " Creates a `{}` with every field set to the given value, after converting it into a `{}`, returning the conversion's error if it fails.",
+            struct_name,
+            type_to_string(input_type),
+        );
 
-            quote!(
-                impl #struct_name {
-                    pub fn splat(v: #shared_type) -> Self {
-                        // we don't actually need the field_idents here, we just need the repetition of the iterator
-                        Self(#(#field_idents v.clone()),*)
+        quote!(
+            #[doc = #doc]
+            #[inline]
+            #vis fn #try_method_name<#input: ::core::convert::TryInto<#input_type>>(
+                v: #input,
+            ) -> ::core::result::Result<Self, <#input as ::core::convert::TryInto<#input_type>>::Error>
+            where
+                #(#bounds,)*
+            {
+                let v: #input_type = ::core::convert::TryInto::try_into(v)?;
+                ::core::result::Result::Ok(#splat_path(#splat_arg))
+            }
+        )
+    });
+
+    // with `validate`, a fallible method checks the splatted struct before returning it
+    let validate_method = container_attrs.validate.as_ref().map(|validate| {
+        let try_method_name = format_ident!("try_{}", method_name);
+        let error = &container_attrs.error;
+        let doc = format!(
+            " Creates a `{}` with every field set to the given `{}`, returning the error from `{}` if the result is invalid.",
+            struct_name,
+            type_to_string(input_type),
+            path_to_string(validate),
+        );
+
+        quote!(
+            #[doc = #doc]
+            #[inline]
+            #vis fn #try_method_name(#param) -> ::core::result::Result<Self, #error>
+            where
+                #(#bounds,)*
+            {
+                let splat = #splat_path(v);
+                #validate(&splat)?;
+                ::core::result::Result::Ok(splat)
+            }
+        )
+    });
+
+    // aliases delegate to the generated method, taking the same parameter
+    let alias_doc = if container_attrs.doc_hidden {
+        quote!(#[doc(hidden)])
+    } else {
+        let doc = format!(" Alias of [`Self::{}`].", method_name);
+        quote!(#[doc = #doc])
+    };
+    let aliases = container_attrs.alias.iter().map(|alias| {
+        quote!(
+            #alias_doc
+            #[inline]
+            #[must_use]
+            #vis #const_token fn #alias(#param) -> Self
+            where
+                #(#bounds,)*
+            {
+                #splat_path(v)
+            }
+        )
+    });
+
+    let members = get_members(&fields);
+    let target = extra::Target {
+        struct_name: &struct_name,
+        generics: &generics,
+        vis: &vis,
+        param_type,
+        fill_type,
+        wrap: &wrappers,
+        array_map: container_attrs
+            .msrv
+            .as_ref()
+            .is_some_and(|msrv| msrv.version < (1, 63)),
+        bounds: &bounds,
+        copy,
+        by_ref: container_attrs.by_ref,
+        fields: members
+            .iter()
+            .zip(&fields)
+            .zip(&field_attrs)
+            .map(|((member, field), attrs)| (member, field, attrs))
+            .collect(),
+        constructor: container_attrs.constructor.as_ref(),
+        packed,
+        param: &param,
+        prelude: &quote!(#conversion #range_check),
+        range_check: &quote!(#range_check),
+        crate_path: container_attrs.crate_path.as_ref(),
+    };
+    let construct = target.construct(&target.param_value());
+
+    // `range` also generates a fallible method rejecting values outside of it
+    let range_method = container_attrs.range.as_ref().map(|range| {
+        let try_method_name = format_ident!("try_{}", method_name);
+        let in_range = range.in_range();
+        let doc = format!(
+            " Creates a `{}` with every field set to the given `{}`, returning the value back as the error if it is outside of `{}`.",
+            struct_name,
+            type_to_string(param_type),
+            range.text,
+        );
+
+        quote!(
+            #[doc = #doc]
+            #[inline]
+            #vis fn #try_method_name(v: #param_type) -> ::core::result::Result<Self, #param_type>
+            where
+                #(#bounds,)*
+            {
+                if !(#in_range) {
+                    return ::core::result::Result::Err(v);
+                }
+                ::core::result::Result::Ok(#construct)
+            }
+        )
+    });
+
+    // the methods requested by `extra(...)`
+    let extra_methods = container_attrs
+        .extra
+        .iter()
+        .map(|method| extra::expand_extra(method, &target));
+    // the associated constants given by `const_value(...)`
+    let const_values = container_attrs
+        .const_value
+        .iter()
+        .map(|const_value| extra::expand_const_value(&target, const_value));
+    // `splat_some` and `splat_none`, when `splat` takes an `Option`
+    let option_methods = extra::expand_option(&target);
+    // `splat_initialized` and `splat_empty`, when `splat` takes a `OnceCell` or `OnceLock`
+    let once_cell_methods = extra::expand_once_cell(&target);
+    // `splat_n`, when `splat` takes a `Vec` or a boxed slice
+    let collection_method = extra::expand_collection(&target);
+    // a checked `try_splat`, when `splat` takes a non-zero integer and no other `try_splat`
+    // is generated
+    let non_zero_method = (!container_attrs.try_into
+        && container_attrs.validate.is_none()
+        && container_attrs.range.is_none())
+    .then(|| extra::expand_non_zero(&target, &method_name))
+    .flatten();
+
+    let allow_lints = quote!(
+        #[allow(
+            clippy::clone_on_copy,
+            clippy::manual_range_contains,
+            clippy::ptr_arg,
+            clippy::redundant_clone
+        )]
+    );
+    let body = quote!(
+        #conversion
+        #range_check
+        #construct
+    );
+
+    // `impl Default`, with `impl_default`
+    let default_impl = container_attrs
+        .impl_default
+        .as_ref()
+        .map(|value| extra::expand_impl_default(&target, value.as_ref(), &allow_lints));
+    // `impl From<T>`, with `impl_from`
+    let from_impl = container_attrs
+        .impl_from
+        .then(|| extra::expand_impl_from(&target, &allow_lints));
+
+    let doc_aliases = &container_attrs.doc_alias;
+
+    // with `impl_trait`, the method implements the given trait rather than being inherent, so
+    // its bounds move to the trait impl
+    let (trait_impl, inherent_method) = match &container_attrs.impl_trait {
+        Some(impl_trait) => {
+            let predicates = generics
+                .where_clause
+                .iter()
+                .flat_map(|where_clause| &where_clause.predicates);
+            let trait_impl = quote!(
+                #[automatically_derived]
+                #allow_lints
+                impl #impl_generics #impl_trait for #struct_name #ty_generics
+                where
+                    #(#predicates,)*
+                    #(#bounds,)*
+                {
+                    #doc
+                    #[inline]
+                    fn #method_name(#param) -> Self {
+                        #body
                     }
                 }
-            )
+            );
+            (Some(trait_impl), None)
         }
-        Fields::Unit => panic!("Splat cannot be derived by unit structs"),
-    })
+        None => {
+            let inherent_method = quote!(
+                #doc
+                #(#[doc(alias = #doc_aliases)])*
+                #[inline]
+                #[must_use]
+                #vis #const_token fn #method_name(#param) -> Self
+                where
+                    #(#bounds,)*
+                {
+                    #body
+                }
+            );
+            (None, Some(inherent_method))
+        }
+    };
+
+    let expansion = quote!(
+        #single_field_warning
+        #bound_assertion
+
+        #trait_impl
+
+        #default_impl
+
+        #from_impl
+
+        #[automatically_derived]
+        #allow_lints
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #(#const_values)*
+
+            #inherent_method
+
+            #(#aliases)*
+
+            #(#extra_methods)*
+
+            #option_methods
+
+            #once_cell_methods
+
+            #collection_method
+
+            #non_zero_method
+
+            #try_method
+
+            #validate_method
+
+            #range_method
+        }
+    );
+
+    Ok(finish_expansion(expansion, &struct_name, &container_attrs))
 }
 
-// gets the type of all of the fields in the struct
-fn get_shared_type(mut fields: Iter<Field>) -> Type {
-    // get the type of the first field
-    let shared_type = match fields.next() {
-        Some(first_field) => first_field.ty.clone(),
-        None => panic!("Splat cannot be derived by structs with no fields"),
+// expands the derive for `grouped`, where each distinct field type gets its own parameter,
+// which fills every field of that type
+fn expand_grouped(
+    struct_name: &Ident,
+    generics: &Generics,
+    fields: &Punctuated<Field, Token![,]>,
+    container_attrs: &ContainerAttrs,
+    field_attrs: &[FieldAttrs],
+    method_name: &Ident,
+    vis: &Visibility,
+) -> Result<TokenStream2> {
+    // the types of the groups, in the order they first appear
+    let mut group_types = Vec::<&Type>::new();
+    for (field, _) in fields
+        .iter()
+        .zip(field_attrs)
+        .filter(|(field, attrs)| is_splat_field(field, attrs))
+    {
+        if !group_types.iter().any(|ty| is_same_type(ty, &field.ty)) {
+            group_types.push(&field.ty);
+        }
+    }
+    if let Some(self_span) = group_types
+        .iter()
+        .find_map(|ty| find_self(ty.to_token_stream()))
+    {
+        return Err(Error::new(
+            self_span,
+            Message::new("Splat cannot be derived by recursive structs, since `splat` would need an instance of the struct to construct one")
+                .explain(EXPLAIN_RECURSIVE),
+        ));
+    }
+    let params = (0..group_types.len())
+        .map(|index| format_ident!("v{}", index))
+        .collect::<Vec<_>>();
+
+    let copy = container_attrs.copy || container_attrs.const_fn;
+    let const_token = container_attrs.const_fn.then(|| quote!(const));
+    let (bound_assertions, bounds): (Vec<_>, Vec<_>) = group_types
+        .iter()
+        .map(|ty| get_bound(ty, generics, copy))
+        .unzip();
+    let bounds = match &container_attrs.bound {
+        Some(predicates) => predicates.iter().map(|p| p.to_token_stream()).collect(),
+        None => bounds,
+    };
+
+    let doc = if container_attrs.doc_hidden {
+        quote!(#[doc(hidden)])
+    } else if let Some(doc) = &container_attrs.doc {
+        quote!(#[doc = #doc])
+    } else {
+        let doc_summary = format!(
+            " Creates a `{}` with every field set to the given value of the field's type.",
+            struct_name,
+        );
+        let doc_details = format!(
+            " Each field receives a {} of the parameter with its type, so `{}::{}` is the same as writing out the struct with that parameter in each field.",
+            if copy { "copy" } else { "clone" },
+            struct_name,
+            method_name,
+        );
+        quote!(
+            #[doc = #doc_summary]
+            #[doc = ""]
+            #[doc = #doc_details]
+        )
     };
 
-    // ensure each other field is also of this type
-    fields.for_each(|field| {
-        if field.ty != shared_type {
-            panic!("Splat can only be derived by structs where each field is the same type");
+    let doc_aliases = &container_attrs.doc_alias;
+
+    let members = get_members(fields);
+    let fills = fields.iter().zip(field_attrs).map(|(field, attrs)| {
+        match group_types
+            .iter()
+            .position(|ty| is_same_type(ty, &field.ty))
+        {
+            _ if is_phantom_data(&field.ty) => quote!(::core::marker::PhantomData),
+            _ if !is_splat_field(field, attrs) => attrs.skipped_init().unwrap_or_default(),
+            Some(group) if copy => params[group].to_token_stream(),
+            Some(group) => {
+                let param = &params[group];
+                quote!(#param.clone())
+            }
+            None => unreachable!("every field belongs to a group"),
         }
     });
+    let cfgs = fields.iter().map(get_cfgs);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote!(
+        #(#bound_assertions)*
+
+        #[automatically_derived]
+        #[allow(clippy::clone_on_copy, clippy::redundant_clone, clippy::too_many_arguments)]
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #doc
+            #(#[doc(alias = #doc_aliases)])*
+            #[inline]
+            #[must_use]
+            #vis #const_token fn #method_name(#(#params: #group_types),*) -> Self
+            where
+                #(#bounds,)*
+            {
+                Self {
+                    #(#(#cfgs)* #members: #fills),*
+                }
+            }
+        }
+    ))
+}
+
+// gates everything generated behind `cfg(...)` by wrapping it in an anonymous const, and
+// reports the result as a warning with `debug_expand`
+fn finish_expansion(
+    expansion: TokenStream2,
+    struct_name: &Ident,
+    container_attrs: &ContainerAttrs,
+) -> TokenStream2 {
+    let expansion = match &container_attrs.cfg {
+        Some(cfg) => quote!(
+            #[cfg(#cfg)]
+            const _: () = {
+                #expansion
+            };
+        ),
+        None => expansion,
+    };
+
+    if !container_attrs.debug_expand {
+        return expansion;
+    }
+    // a warning is reported like any other diagnostic, rather than printed to stderr every
+    // time the macro runs, including in IDEs
+    let warning = diagnostic::warn(
+        struct_name.span(),
+        Message::new(format!("splat expansion for `{}`", struct_name)).note(&expansion),
+    );
+    quote!(
+        #expansion
+        #warning
+    )
+}
+
+// gets the type of all of the fields in the struct, which must not be empty
+fn get_shared_type(fields: &[&Field]) -> Result<Type> {
+    // get the type of the first field
+    let shared_type = fields[0].ty.clone();
+    let other_fields = &fields[1..];
+
+    // if every field is of this type, it is the shared type
+    if other_fields
+        .iter()
+        .all(|field| is_same_type(&field.ty, &shared_type))
+    {
+        return Ok(shared_type);
+    }
+
+    // otherwise each field must be a tuple or array built from the same leaf type
+    let leaf_type = get_leaf_type(&shared_type);
+    let is_compatible = |ty: &Type| match (get_leaf_type(ty), &leaf_type) {
+        _ if is_same_type(ty, &shared_type) => true,
+        (Some(a), Some(b)) => is_same_type(&a, b),
+        _ => false,
+    };
+
+    // point at the type of every field that doesn't fit, reporting them all at once
+    combine_errors(
+        other_fields
+            .iter()
+            .filter(|field| !is_compatible(&field.ty))
+            .map(|field| get_mismatch_error(&shared_type, field)),
+    )?;
+
+    Ok(leaf_type.expect("compatible fields share a leaf type"))
+}
+
+// combines errors so they are all reported at once, succeeding if there are none
+fn combine_errors(errors: impl IntoIterator<Item = Error>) -> Result<()> {
+    errors
+        .into_iter()
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+        .map_or(Ok(()), Err)
+}
+
+// builds an error showing both types, highlighting the innermost part where they differ
+fn get_mismatch_error(expected: &Type, found: &Field) -> Error {
+    let field_name = match &found.ident {
+        Some(ident) => format!("field `{}`", ident),
+        None => String::from("this field"),
+    };
+    let mut message =
+        Message::new("Splat can only be derived by structs where each field is the same type")
+            .note(format!(
+                "{} has type `{}`, expected `{}`",
+                field_name,
+                type_to_string(&found.ty),
+                type_to_string(expected),
+            ));
+
+    let (expected_part, found_part) = get_type_difference(expected, &found.ty);
+    if !std::ptr::eq(found_part, &found.ty) {
+        message = message.note(format!(
+            "`{}` differs from `{}`",
+            type_to_string(found_part),
+            type_to_string(expected_part),
+        ));
+    }
+
+    for (option, workaround) in MISMATCH_WORKAROUNDS {
+        message = message.help(format!(
+            "add `#[splat({})]` to the field to {}",
+            option, workaround
+        ));
+    }
+
+    Error::new_spanned(found_part, message.explain(EXPLAIN_SAME_TYPE))
+}
+
+// descends through two types while their structure matches, returning the innermost
+// pair of types that differ
+fn get_type_difference<'a>(expected: &'a Type, found: &'a Type) -> (&'a Type, &'a Type) {
+    // finds the only differing pair out of a list of pairs, if there is exactly one
+    fn single_difference<'a>(
+        pairs: impl Iterator<Item = (&'a Type, &'a Type)>,
+    ) -> Option<(&'a Type, &'a Type)> {
+        let mut differences = pairs.filter(|(expected, found)| !is_same_type(expected, found));
+        match (differences.next(), differences.next()) {
+            (Some(difference), None) => Some(difference),
+            _ => None,
+        }
+    }
+
+    let difference = match (expected, found) {
+        (Type::Group(group), _) => return get_type_difference(&group.elem, found),
+        (_, Type::Group(group)) => return get_type_difference(expected, &group.elem),
+        (Type::Paren(expected), Type::Paren(found)) => Some((&*expected.elem, &*found.elem)),
+        (Type::Array(expected), Type::Array(found)) if expected.len == found.len => {
+            Some((&*expected.elem, &*found.elem))
+        }
+        (Type::Slice(expected), Type::Slice(found)) => Some((&*expected.elem, &*found.elem)),
+        (Type::Reference(expected), Type::Reference(found))
+            if expected.lifetime == found.lifetime && expected.mutability == found.mutability =>
+        {
+            Some((&*expected.elem, &*found.elem))
+        }
+        (Type::Tuple(expected), Type::Tuple(found))
+            if expected.elems.len() == found.elems.len() =>
+        {
+            single_difference(expected.elems.iter().zip(found.elems.iter()))
+        }
+        (Type::Path(expected), Type::Path(found))
+            if expected.qself == found.qself
+                && expected.path.segments.len() == found.path.segments.len() =>
+        {
+            let segments = expected.path.segments.iter().zip(&found.path.segments);
+            let mut type_args = Vec::new();
+            let mut same_shape = true;
+            for (expected, found) in segments {
+                match (&expected.arguments, &found.arguments) {
+                    _ if expected.ident != found.ident => same_shape = false,
+                    (
+                        PathArguments::AngleBracketed(expected),
+                        PathArguments::AngleBracketed(found),
+                    ) if expected.args.len() == found.args.len() => {
+                        for args in expected.args.iter().zip(&found.args) {
+                            match args {
+                                (GenericArgument::Type(expected), GenericArgument::Type(found)) => {
+                                    type_args.push((expected, found))
+                                }
+                                (expected, found) => same_shape &= expected == found,
+                            }
+                        }
+                    }
+                    (expected, found) => same_shape &= expected == found,
+                }
+            }
+            same_shape
+                .then(|| single_difference(type_args.into_iter()))
+                .flatten()
+        }
+        _ => None,
+    };
+
+    match difference {
+        Some((expected, found)) => get_type_difference(expected, found),
+        None => (expected, found),
+    }
+}
+
+// formats a type roughly the way it would be written in source
+fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" ;", ";")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace("& ", "&")
+}
+
+// gets the type at the leaves of nested tuples and arrays, if all of the leaves agree
+fn get_leaf_type(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Array(array) => get_leaf_type(&array.elem),
+        Type::Paren(paren) => get_leaf_type(&paren.elem),
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            let mut leaf_types = tuple.elems.iter().map(get_leaf_type);
+            let leaf_type = leaf_types.next()??;
+            leaf_types
+                .all(|other| other.is_some_and(|other| is_same_type(&other, &leaf_type)))
+                .then_some(leaf_type)
+        }
+        _ => Some(ty.clone()),
+    }
+}
+
+// compares two types, seeing through the invisible groups left by macro_rules
+// expansions and comparing type macros by their tokens rather than their delimiters
+fn is_same_type(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::Group(group), _) => is_same_type(&group.elem, b),
+        (_, Type::Group(group)) => is_same_type(a, &group.elem),
+        (Type::Macro(a), Type::Macro(b)) => {
+            a.mac.path == b.mac.path && a.mac.tokens.to_string() == b.mac.tokens.to_string()
+        }
+        _ => a == b,
+    }
+}
+
+// checks whether a field is a PhantomData marker, however its path is written and even when it
+// is wrapped in parentheses or an invisible group from a macro
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Group(group) => is_phantom_data(&group.elem),
+        Type::Paren(paren) => is_phantom_data(&paren.elem),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+// checks whether the struct is `#[repr(packed)]`, whose fields can't be borrowed since they
+// may be unaligned
+fn is_packed(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("packed"))
+}
+
+// gets the `#[cfg(...)]` attributes of a field, which are copied onto everything generated for
+// it so that fields compiled out aren't initialized
+fn get_cfgs(field: &Field) -> Vec<&Attribute> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect()
+}
+
+// checks whether a type is a `Cow`, which can be built from a borrowed or an owned value
+fn is_cow(ty: &Type) -> bool {
+    match ty {
+        Type::Group(group) => is_cow(&group.elem),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Cow"),
+        _ => false,
+    }
+}
+
+// checks whether a type is a reference counted pointer, whose clones share the value
+fn is_shared_pointer(ty: &Type) -> bool {
+    match ty {
+        Type::Group(group) => is_shared_pointer(&group.elem),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Rc" || segment.ident == "Arc"),
+        _ => false,
+    }
+}
+
+// checks whether a field takes part in the splat, which PhantomData and skipped fields don't
+fn is_splat_field(field: &Field, attrs: &FieldAttrs) -> bool {
+    !is_phantom_data(&field.ty) && attrs.skipped_init().is_none()
+}
+
+// gets the type taken by splat, which is the element type for arrays sized by a const generic
+fn get_param_type<'a>(shared_type: &'a Type, generics: &Generics) -> &'a Type {
+    match shared_type {
+        Type::Array(array) if is_const_param(&array.len, generics) => {
+            get_param_type(&array.elem, generics)
+        }
+        _ => shared_type,
+    }
+}
+
+// gets the member initializing each field, which is its index for tuple structs, e.g.
+// `Self { 0: v.clone() }`
+fn get_members(fields: &Punctuated<Field, Token![,]>) -> Vec<Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        })
+        .collect()
+}
+
+// gets the bound requiring `ty` to be Clone, or Copy when `copy` is set, along with a static
+// assertion for types without generics, whose bound would otherwise be reported at the
+// derive attribute, so it is checked with a span on the type itself instead, and the bound
+// is made higher-ranked so the compiler doesn't check it a second time
+fn get_bound(ty: &Type, generics: &Generics, copy: bool) -> (Option<TokenStream2>, TokenStream2) {
+    let (bound_trait, assert_fn) = if copy {
+        (quote!(::core::marker::Copy), format_ident!("assert_copy"))
+    } else {
+        (quote!(::core::clone::Clone), format_ident!("assert_clone"))
+    };
+
+    if references_generics(ty, generics) {
+        return (None, quote!(#ty: #bound_trait));
+    }
+    let bound_assertion = quote_spanned!(ty.span()=>
+        const _: fn() = || {
+            fn #assert_fn<T: ?::core::marker::Sized + #bound_trait>() {}
+            #assert_fn::<#ty>();
+        };
+    );
+    (
+        Some(bound_assertion),
+        quote!(for<'__splat> #ty: #bound_trait),
+    )
+}
+
+// gets the constructors of the std wrappers that `ty` is built from, outermost first, like
+// `Box::new` and `RefCell::new` for `Box<RefCell<T>>`, along with the type they are built from
+fn get_auto_wrappers(mut ty: &Type) -> (Vec<Path>, Type) {
+    // `Rc` and `Arc` are left out, since their fields usually share the pointer given to
+    // `splat` rather than each getting a new one
+    const AUTO_WRAPPERS: &[&str] = &["Box", "Cell", "RefCell", "Mutex", "RwLock"];
+
+    let mut wrappers = Vec::new();
+    while let Type::Path(type_path) = ty {
+        let Some(segment) = type_path.path.segments.last() else {
+            break;
+        };
+        if type_path.qself.is_some() {
+            break;
+        }
+
+        // atomics are built from the primitive they hold, and can't wrap anything else
+        let atomic_type = get_atomic_type(segment);
+        let wrapped = if atomic_type.is_some() {
+            None
+        } else if AUTO_WRAPPERS.iter().any(|name| segment.ident == name) {
+            match get_wrapped_type(ty, &type_path.path) {
+                Ok(wrapped) if !is_unsized(wrapped) => Some(wrapped),
+                _ => break,
+            }
+        } else {
+            break;
+        };
+
+        let mut constructor = type_path.path.clone();
+        if let Some(segment) = constructor.segments.last_mut() {
+            segment.arguments = PathArguments::None;
+        }
+        constructor.segments.push(format_ident!("new").into());
+        wrappers.push(constructor);
+
+        match (atomic_type, wrapped) {
+            (Some(atomic_type), _) => return (wrappers, atomic_type),
+            (None, Some(wrapped)) => ty = wrapped,
+            (None, None) => break,
+        }
+    }
+    (wrappers, ty.clone())
+}
+
+// checks whether a type is known to be unsized, so it can't be passed by value, like the
+// `[T]` in `Box<[T]>`
+fn is_unsized(ty: &Type) -> bool {
+    match ty {
+        Type::Group(group) => is_unsized(&group.elem),
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+// gets the value type of a `core::sync::atomic` type, like `u32` for `AtomicU32` and `*mut T`
+// for `AtomicPtr<T>`
+fn get_atomic_type(segment: &PathSegment) -> Option<Type> {
+    const ATOMICS: &[&str] = &[
+        "AtomicBool",
+        "AtomicI8",
+        "AtomicI16",
+        "AtomicI32",
+        "AtomicI64",
+        "AtomicIsize",
+        "AtomicU8",
+        "AtomicU16",
+        "AtomicU32",
+        "AtomicU64",
+        "AtomicUsize",
+    ];
+
+    let name = segment.ident.to_string();
+    if name == "AtomicPtr" {
+        let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return None;
+        };
+        return match arguments.args.first() {
+            Some(GenericArgument::Type(pointee)) => Some(parse_quote!(*mut #pointee)),
+            _ => None,
+        };
+    }
+    if !ATOMICS.contains(&name.as_str()) || !segment.arguments.is_none() {
+        return None;
+    }
+    let primitive = format_ident!("{}", name["Atomic".len()..].to_lowercase());
+    Some(parse_quote!(#primitive))
+}
+
+// gets the type the given wrapper functions take to build `ty`, outermost first
+fn get_unwrapped_type<'a>(ty: &'a Type, wrap: &[Path]) -> Result<&'a Type> {
+    wrap.iter()
+        .try_fold(ty, |ty, wrap| get_wrapped_type(ty, wrap))
+}
+
+// gets the type a wrapper function takes to build `ty`, which is its only type argument, like
+// `T` in `Arc<T>`
+fn get_wrapped_type<'a>(ty: &'a Type, wrap: &Path) -> Result<&'a Type> {
+    let wrapped_type = match ty {
+        Type::Group(group) => return get_wrapped_type(&group.elem, wrap),
+        Type::Path(type_path) => type_path.path.segments.last().and_then(|segment| {
+            let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return None;
+            };
+            let mut types = arguments.args.iter().filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+            match (types.next(), types.next()) {
+                (Some(ty), None) => Some(ty),
+                _ => None,
+            }
+        }),
+        _ => None,
+    };
+
+    wrapped_type.ok_or_else(|| {
+        Error::new_spanned(
+            ty,
+            Message::new(format!(
+                "`{}` given to `wrap` needs a type wrapping a single type, like `Arc<T>`",
+                path_to_string(wrap),
+            ))
+            .note(format!("found `{}`", type_to_string(ty))),
+        )
+    })
+}
+
+// formats a path the way it would be written in source
+fn path_to_string(path: &Path) -> String {
+    path.to_token_stream().to_string().replace(' ', "")
+}
+
+// gets the expression used to fill a field of type `ty` with `leaf`, filling every leaf of
+// nested tuples and arrays when `ty` is built from the parameter type, building arrays by
+// repetition when `repeat` says the leaf is a Copy value, and by mapping an array of units
+// rather than with `array::from_fn` when `array_map` is set for older toolchains
+fn get_fill(
+    ty: &Type,
+    param_type: &Type,
+    leaf: &TokenStream2,
+    repeat: bool,
+    array_map: bool,
+) -> TokenStream2 {
+    let fill_elem = |elem| get_fill(elem, param_type, leaf, repeat, array_map);
+
+    match ty {
+        _ if is_same_type(ty, param_type) => leaf.clone(),
+        // arrays of Copy values can be built by repetition, which also works in const fns
+        Type::Array(array) if repeat => {
+            let elem_fill = fill_elem(&array.elem);
+            let len = &array.len;
+            quote!([#elem_fill; #len])
+        }
+        Type::Array(array) if array_map => {
+            let elem_fill = fill_elem(&array.elem);
+            let len = &array.len;
+            quote!([(); #len].map(|()| #elem_fill))
+        }
+        Type::Array(array) => {
+            let elem_fill = fill_elem(&array.elem);
+            quote!(::core::array::from_fn(|_| #elem_fill))
+        }
+        Type::Paren(paren) => fill_elem(&paren.elem),
+        Type::Tuple(tuple) => {
+            let elem_fills = tuple.elems.iter().map(fill_elem);
+            quote!((#(#elem_fills,)*))
+        }
+        _ => leaf.clone(),
+    }
+}
+
+// finds the span of the first `Self` in a type, if it has one
+fn find_self(tokens: TokenStream2) -> Option<Span> {
+    tokens.into_iter().find_map(|token| match token {
+        TokenTree::Group(group) => find_self(group.stream()),
+        TokenTree::Ident(ident) if ident == "Self" => Some(ident.span()),
+        _ => None,
+    })
+}
+
+// checks whether a type mentions any of the struct's generic parameters
+fn references_generics(ty: &Type, generics: &Generics) -> bool {
+    fn contains_ident(tokens: TokenStream2, generics: &Generics) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Group(group) => contains_ident(group.stream(), generics),
+            // lifetimes are a `'` followed by an ident, so their idents are checked here too
+            TokenTree::Ident(ident) => generics.params.iter().any(|param| match param {
+                GenericParam::Type(type_param) => ident == type_param.ident,
+                GenericParam::Lifetime(lifetime_param) => ident == lifetime_param.lifetime.ident,
+                GenericParam::Const(const_param) => ident == const_param.ident,
+            }),
+            _ => false,
+        })
+    }
+
+    contains_ident(ty.to_token_stream(), generics)
+}
+
+// gets a name for a generic parameter of a generated method, which doesn't clash with the
+// struct's own generic parameters
+fn get_fresh_param(generics: &Generics, name: &str) -> Ident {
+    let mut param = format_ident!("{}", name);
+    while generics
+        .type_params()
+        .any(|type_param| type_param.ident == param)
+    {
+        param = format_ident!("_{}", param);
+    }
+    param
+}
+
+// checks whether an array length is one of the struct's const generic parameters
+fn is_const_param(len: &Expr, generics: &Generics) -> bool {
+    let len = match len {
+        Expr::Path(expr_path) => &expr_path.path,
+        _ => return false,
+    };
 
-    shared_type
+    generics
+        .const_params()
+        .any(|const_param| len.is_ident(&const_param.ident))
 }
@@ -25,3 +25,2120 @@ fn tuple_struct_fields() {
     assert_eq!(test_tuple_struct.1, -1_000_000);
     assert_eq!(test_tuple_struct.2, -1_000_000);
 }
+
+#[derive(Splat)]
+struct TestGenericStruct<T> {
+    field_one: T,
+    field_two: T,
+}
+
+#[test]
+fn generic_struct_fields() {
+    let test_generic_struct = TestGenericStruct::splat(String::from("splat"));
+    assert_eq!(test_generic_struct.field_one, "splat");
+    assert_eq!(test_generic_struct.field_two, "splat");
+}
+
+struct NotClone(u8);
+
+#[derive(Splat)]
+struct TestLifetimeStruct<'a, T> {
+    field_one: &'a T,
+    field_two: &'a T,
+}
+
+#[test]
+fn lifetime_struct_fields() {
+    let not_clone = NotClone(7);
+    let test_lifetime_struct = TestLifetimeStruct::splat(&not_clone);
+    assert_eq!(test_lifetime_struct.field_one.0, 7);
+    assert_eq!(test_lifetime_struct.field_two.0, 7);
+}
+
+#[derive(Splat)]
+struct TestConstGenericStruct<const N: usize> {
+    field_one: [f32; N],
+    field_two: [f32; N],
+}
+
+#[test]
+fn const_generic_array_fields() {
+    let test_const_generic_struct = TestConstGenericStruct::<4>::splat(0.5);
+    assert_eq!(test_const_generic_struct.field_one, [0.5; 4]);
+    assert_eq!(test_const_generic_struct.field_two, [0.5; 4]);
+}
+
+#[derive(Splat)]
+struct TestWhereClauseStruct<T>
+where
+    T: Copy + Into<u64>,
+{
+    field_one: T,
+    field_two: T,
+}
+
+#[test]
+fn where_clause_struct_fields() {
+    let test_where_clause_struct = TestWhereClauseStruct::splat(9_u32);
+    assert_eq!(Into::<u64>::into(test_where_clause_struct.field_one), 9);
+    assert_eq!(Into::<u64>::into(test_where_clause_struct.field_two), 9);
+}
+
+#[derive(Splat)]
+struct TestDefaultParamStruct<T = f32> {
+    field_one: T,
+    field_two: T,
+}
+
+#[test]
+fn default_param_struct_fields() {
+    let test_default_param_struct: TestDefaultParamStruct = TestDefaultParamStruct::splat(1.5);
+    assert_eq!(test_default_param_struct.field_one, 1.5);
+    assert_eq!(test_default_param_struct.field_two, 1.5);
+}
+
+struct Marker;
+
+#[derive(Splat)]
+struct TestExtraParamStruct<T, M> {
+    field_one: T,
+    field_two: T,
+    _marker: std::marker::PhantomData<M>,
+}
+
+#[test]
+fn extra_param_struct_fields() {
+    let test_extra_param_struct = TestExtraParamStruct::<u8, Marker>::splat(3);
+    assert_eq!(test_extra_param_struct.field_one, 3);
+    assert_eq!(test_extra_param_struct.field_two, 3);
+}
+
+#[derive(Splat)]
+struct TestCompositeStruct {
+    field_one: u8,
+    field_two: (u8, u8),
+    field_three: ((u8, u8), [u8; 3]),
+}
+
+#[test]
+fn composite_struct_fields() {
+    let test_composite_struct = TestCompositeStruct::splat(4);
+    assert_eq!(test_composite_struct.field_one, 4);
+    assert_eq!(test_composite_struct.field_two, (4, 4));
+    assert_eq!(test_composite_struct.field_three, ((4, 4), [4, 4, 4]));
+}
+
+macro_rules! byte {
+    () => {
+        u8
+    };
+}
+
+#[derive(Splat)]
+struct TestTypeMacroStruct {
+    field_one: byte!(),
+    field_two: byte![],
+}
+
+#[test]
+fn type_macro_struct_fields() {
+    let test_type_macro_struct = TestTypeMacroStruct::splat(6);
+    assert_eq!(test_type_macro_struct.field_one, 6);
+    assert_eq!(test_type_macro_struct.field_two, 6);
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Splat)]
+struct r#struct {
+    r#type: u8,
+    r#fn: u8,
+}
+
+#[test]
+fn raw_identifier_struct_fields() {
+    let raw_identifier_struct = r#struct::splat(8);
+    assert_eq!(raw_identifier_struct.r#type, 8);
+    assert_eq!(raw_identifier_struct.r#fn, 8);
+}
+
+#[derive(Splat)]
+#[splat(allow_single_field)]
+struct TestSingleFieldStruct(u64);
+
+#[test]
+fn single_field_struct_fields() {
+    let test_single_field_struct = TestSingleFieldStruct::splat(12);
+    assert_eq!(test_single_field_struct.0, 12);
+}
+
+#[derive(Splat)]
+#[splat(rename = "broadcast")]
+struct TestRenameStruct {
+    field_one: u8,
+    field_two: u8,
+}
+
+impl TestRenameStruct {
+    fn splat() -> u8 {
+        0
+    }
+}
+
+#[test]
+fn rename_struct_fields() {
+    let test_rename_struct = TestRenameStruct::broadcast(5);
+    assert_eq!(test_rename_struct.field_one, 5);
+    assert_eq!(test_rename_struct.field_two, 5);
+    assert_eq!(TestRenameStruct::splat(), 0);
+}
+
+mod vis {
+    use splat_derive::Splat;
+
+    #[derive(Splat)]
+    pub struct TestInheritedVisStruct {
+        pub field_one: u8,
+        pub field_two: u8,
+    }
+
+    #[derive(Splat)]
+    #[splat(vis = "pub(crate)")]
+    pub struct TestVisStruct {
+        pub field_one: u8,
+        pub field_two: u8,
+    }
+}
+
+#[test]
+fn vis_struct_fields() {
+    let test_vis_struct = vis::TestVisStruct::splat(10);
+    assert_eq!(test_vis_struct.field_one, 10);
+    assert_eq!(test_vis_struct.field_two, 10);
+}
+
+#[test]
+fn inherited_vis_struct_fields() {
+    let test_inherited_vis_struct = vis::TestInheritedVisStruct::splat(11);
+    assert_eq!(test_inherited_vis_struct.field_one, 11);
+    assert_eq!(test_inherited_vis_struct.field_two, 11);
+}
+
+#[derive(Splat)]
+#[splat(const_fn)]
+struct TestConstFnStruct<const N: usize> {
+    field_one: [u32; N],
+    field_two: ([u32; 2], u32),
+}
+
+const TEST_CONST_FN_STRUCT: TestConstFnStruct<3> = TestConstFnStruct::splat(13);
+
+#[test]
+fn const_fn_struct_fields() {
+    assert_eq!(TEST_CONST_FN_STRUCT.field_one, [13; 3]);
+    assert_eq!(TEST_CONST_FN_STRUCT.field_two, ([13, 13], 13));
+}
+
+#[derive(Splat)]
+#[splat(doc = "Fills the struct with a single value.")]
+struct TestDocStruct {
+    field_one: i8,
+    field_two: i8,
+}
+
+#[derive(Splat)]
+#[splat(doc_hidden)]
+struct TestDocHiddenStruct {
+    field_one: i8,
+    field_two: i8,
+}
+
+#[test]
+fn doc_struct_fields() {
+    let test_doc_struct = TestDocStruct::splat(-3);
+    assert_eq!(test_doc_struct.field_one, -3);
+    assert_eq!(test_doc_struct.field_two, -3);
+
+    let test_doc_hidden_struct = TestDocHiddenStruct::splat(-4);
+    assert_eq!(test_doc_hidden_struct.field_one, -4);
+    assert_eq!(test_doc_hidden_struct.field_two, -4);
+}
+
+#[derive(Splat)]
+#[splat(into)]
+struct TestIntoStruct {
+    field_one: String,
+    field_two: String,
+}
+
+#[test]
+fn into_struct_fields() {
+    let test_into_struct = TestIntoStruct::splat("red");
+    assert_eq!(test_into_struct.field_one, "red");
+    assert_eq!(test_into_struct.field_two, "red");
+}
+
+#[derive(Splat)]
+#[splat(try_into)]
+struct TestTryIntoStruct<V> {
+    field_one: u8,
+    field_two: u8,
+    _marker: std::marker::PhantomData<V>,
+}
+
+#[test]
+fn try_into_struct_fields() {
+    let test_try_into_struct = TestTryIntoStruct::<()>::try_splat(200_usize).unwrap();
+    assert_eq!(test_try_into_struct.field_one, 200);
+    assert_eq!(test_try_into_struct.field_two, 200);
+    assert!(TestTryIntoStruct::<()>::try_splat(300_usize).is_err());
+}
+
+#[derive(Splat)]
+#[splat(by_ref, try_into)]
+struct TestByRefStruct {
+    field_one: Vec<u8>,
+    field_two: Vec<u8>,
+}
+
+#[test]
+fn by_ref_struct_fields() {
+    let value = vec![1, 2, 3];
+    let test_by_ref_struct = TestByRefStruct::splat(&value);
+    assert_eq!(test_by_ref_struct.field_one, value);
+    assert_eq!(test_by_ref_struct.field_two, value);
+
+    let test_by_ref_struct = TestByRefStruct::try_splat(value.clone()).unwrap();
+    assert_eq!(test_by_ref_struct.field_one, value);
+}
+
+#[derive(Splat)]
+#[splat(copy)]
+struct TestCopyStruct<T> {
+    field_one: T,
+    field_two: [T; 2],
+}
+
+#[test]
+fn copy_struct_fields() {
+    let test_copy_struct = TestCopyStruct::splat('c');
+    assert_eq!(test_copy_struct.field_one, 'c');
+    assert_eq!(test_copy_struct.field_two, ['c', 'c']);
+}
+
+#[derive(Splat)]
+#[splat(bound = "T: Copy + Default")]
+struct TestBoundStruct<T> {
+    field_one: T,
+    field_two: T,
+}
+
+#[test]
+fn bound_struct_fields() {
+    let test_bound_struct = TestBoundStruct::splat(1.25);
+    assert_eq!(test_bound_struct.field_one, 1.25);
+    assert_eq!(test_bound_struct.field_two, 1.25);
+}
+
+#[derive(Splat)]
+#[splat(cfg(test))]
+struct TestCfgStruct {
+    field_one: u8,
+    field_two: u8,
+}
+
+#[derive(Splat)]
+#[splat(cfg(any()))]
+struct TestCfgDisabledStruct {
+    field_one: u8,
+    field_two: u8,
+}
+
+impl TestCfgDisabledStruct {
+    // only compiles because the derived `splat` was configured out
+    fn splat(v: u8) -> Self {
+        Self {
+            field_one: v,
+            field_two: v + 1,
+        }
+    }
+}
+
+#[test]
+fn cfg_struct_fields() {
+    let test_cfg_struct = TestCfgStruct::splat(14);
+    assert_eq!(test_cfg_struct.field_one, 14);
+    assert_eq!(test_cfg_struct.field_two, 14);
+
+    let test_cfg_disabled_struct = TestCfgDisabledStruct::splat(14);
+    assert_eq!(test_cfg_disabled_struct.field_one, 14);
+    assert_eq!(test_cfg_disabled_struct.field_two, 15);
+}
+
+#[derive(Splat)]
+#[splat(input = "u64", try_into)]
+struct TestInputStruct {
+    field_one: u128,
+    field_two: (u128, u128),
+}
+
+#[test]
+fn input_struct_fields() {
+    let test_input_struct = TestInputStruct::splat(u64::MAX);
+    assert_eq!(test_input_struct.field_one, u64::MAX as u128);
+    assert_eq!(
+        test_input_struct.field_two,
+        (u64::MAX as u128, u64::MAX as u128)
+    );
+
+    let test_input_struct = TestInputStruct::try_splat(16_i32).unwrap();
+    assert_eq!(test_input_struct.field_one, 16);
+    assert!(TestInputStruct::try_splat(-1_i32).is_err());
+}
+
+#[derive(Splat)]
+#[splat(alias = "broadcast, fill", into)]
+struct TestAliasStruct {
+    field_one: String,
+    field_two: String,
+}
+
+#[test]
+fn alias_struct_fields() {
+    let test_alias_struct = TestAliasStruct::broadcast("blue");
+    assert_eq!(test_alias_struct.field_one, "blue");
+    assert_eq!(test_alias_struct.field_two, "blue");
+
+    let test_alias_struct = TestAliasStruct::fill("green");
+    assert_eq!(test_alias_struct.field_two, "green");
+
+    let test_alias_struct = TestAliasStruct::splat("cyan");
+    assert_eq!(test_alias_struct.field_one, "cyan");
+}
+
+#[derive(Splat)]
+#[splat(extra(fill, splat_with, splat_default))]
+struct TestExtraStruct<T> {
+    field_one: T,
+    field_two: [T; 2],
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[test]
+fn extra_struct_fields() {
+    let mut test_extra_struct = TestExtraStruct::<u8>::splat_default();
+    assert_eq!(test_extra_struct.field_one, 0);
+    assert_eq!(test_extra_struct.field_two, [0, 0]);
+
+    test_extra_struct.fill(15);
+    assert_eq!(test_extra_struct.field_one, 15);
+    assert_eq!(test_extra_struct.field_two, [15, 15]);
+
+    let mut count = 0;
+    let test_extra_struct = TestExtraStruct::splat_with(|| {
+        count += 1;
+        count
+    });
+    assert_eq!(test_extra_struct.field_one, 1);
+    assert_eq!(test_extra_struct.field_two, [2, 3]);
+}
+
+mod constructor {
+    use splat_derive::Splat;
+
+    #[derive(Splat)]
+    #[splat(constructor = "new", extra(fill))]
+    pub struct TestConstructorStruct {
+        field_one: u8,
+        field_two: u8,
+    }
+
+    impl TestConstructorStruct {
+        // keeps every field at most 100
+        fn new(field_one: u8, field_two: u8) -> Self {
+            Self {
+                field_one: field_one.min(100),
+                field_two: field_two.min(100),
+            }
+        }
+
+        pub fn fields(&self) -> (u8, u8) {
+            (self.field_one, self.field_two)
+        }
+    }
+}
+
+#[test]
+fn constructor_struct_fields() {
+    let mut test_constructor_struct = constructor::TestConstructorStruct::splat(200);
+    assert_eq!(test_constructor_struct.fields(), (100, 100));
+
+    test_constructor_struct.fill(3);
+    assert_eq!(test_constructor_struct.fields(), (3, 3));
+}
+
+#[derive(Splat)]
+#[splat(wrap = "std::sync::Arc::new", extra(fill))]
+struct TestWrapStruct {
+    field_one: std::sync::Arc<String>,
+    field_two: (std::sync::Arc<String>, [std::sync::Arc<String>; 2]),
+}
+
+#[test]
+fn wrap_struct_fields() {
+    let mut test_wrap_struct = TestWrapStruct::splat(String::from("shared"));
+    assert_eq!(*test_wrap_struct.field_one, "shared");
+    assert_eq!(*test_wrap_struct.field_two.1[1], "shared");
+
+    test_wrap_struct.fill(String::from("filled"));
+    assert_eq!(*test_wrap_struct.field_two.0, "filled");
+}
+
+#[derive(Splat)]
+#[splat(wrap = "std::rc::Rc::new, std::cell::RefCell::new")]
+struct TestWrapChainStruct {
+    field_one: std::rc::Rc<std::cell::RefCell<u8>>,
+    field_two: std::rc::Rc<std::cell::RefCell<u8>>,
+}
+
+#[test]
+fn wrap_chain_struct_fields() {
+    let test_wrap_chain_struct = TestWrapChainStruct::splat(16);
+    *test_wrap_chain_struct.field_one.borrow_mut() += 1;
+    assert_eq!(*test_wrap_chain_struct.field_one.borrow(), 17);
+    assert_eq!(*test_wrap_chain_struct.field_two.borrow(), 16);
+}
+
+#[derive(Splat)]
+struct TestDeepInnerStruct {
+    field_one: f32,
+    field_two: f32,
+}
+
+#[derive(Splat)]
+#[splat(deep = "f32")]
+struct TestDeepStruct {
+    field_one: TestDeepInnerStruct,
+    field_two: [TestDeepInnerStruct; 2],
+}
+
+#[test]
+fn deep_struct_fields() {
+    let test_deep_struct = TestDeepStruct::splat(2.5);
+    assert_eq!(test_deep_struct.field_one.field_one, 2.5);
+    assert_eq!(test_deep_struct.field_one.field_two, 2.5);
+    assert_eq!(test_deep_struct.field_two[1].field_two, 2.5);
+}
+
+#[derive(Splat)]
+#[splat(grouped)]
+struct TestGroupedStruct<T> {
+    field_one: u8,
+    field_two: T,
+    field_three: u8,
+    field_four: (u8, u8),
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[test]
+fn grouped_struct_fields() {
+    let test_grouped_struct = TestGroupedStruct::splat(17, String::from("grouped"), (1, 2));
+    assert_eq!(test_grouped_struct.field_one, 17);
+    assert_eq!(test_grouped_struct.field_two, "grouped");
+    assert_eq!(test_grouped_struct.field_three, 17);
+    assert_eq!(test_grouped_struct.field_four, (1, 2));
+}
+
+#[derive(Debug, PartialEq)]
+struct TestValidateError;
+
+#[derive(Debug, Splat)]
+#[splat(validate = "Self::validate", error = "TestValidateError")]
+struct TestValidateStruct {
+    field_one: f64,
+    field_two: f64,
+}
+
+impl TestValidateStruct {
+    fn validate(&self) -> Result<(), TestValidateError> {
+        if self.field_one.is_finite() && self.field_two.is_finite() {
+            Ok(())
+        } else {
+            Err(TestValidateError)
+        }
+    }
+}
+
+#[test]
+fn validate_struct_fields() {
+    let test_validate_struct = TestValidateStruct::try_splat(0.75).unwrap();
+    assert_eq!(test_validate_struct.field_one, 0.75);
+    assert_eq!(test_validate_struct.field_two, 0.75);
+    assert_eq!(
+        TestValidateStruct::try_splat(f64::NAN).unwrap_err(),
+        TestValidateError
+    );
+}
+
+#[derive(Splat)]
+#[splat(clamp = "0.0..=1.0")]
+struct TestClampStruct {
+    field_one: f32,
+    field_two: f32,
+}
+
+#[derive(Debug, Splat)]
+#[splat(range = "-10..=10")]
+struct TestRangeStruct {
+    field_one: i32,
+    field_two: i32,
+}
+
+#[test]
+fn clamp_struct_fields() {
+    let test_clamp_struct = TestClampStruct::splat(1.5);
+    assert_eq!(test_clamp_struct.field_one, 1.0);
+    assert_eq!(test_clamp_struct.field_two, 1.0);
+
+    let test_clamp_struct = TestClampStruct::splat(-0.5);
+    assert_eq!(test_clamp_struct.field_one, 0.0);
+
+    let test_clamp_struct = TestClampStruct::splat(0.25);
+    assert_eq!(test_clamp_struct.field_two, 0.25);
+}
+
+#[test]
+fn range_struct_fields() {
+    let test_range_struct = TestRangeStruct::splat(-10);
+    assert_eq!(test_range_struct.field_one, -10);
+    assert_eq!(test_range_struct.field_two, -10);
+
+    assert_eq!(TestRangeStruct::try_splat(11).unwrap_err(), 11);
+    assert!(std::panic::catch_unwind(|| TestRangeStruct::splat(11)).is_err());
+}
+
+#[derive(Splat)]
+#[splat(msrv = "1.56", extra(splat_with))]
+struct TestMsrvStruct<const N: usize> {
+    field_one: [String; N],
+    field_two: ([String; 2], String),
+}
+
+#[test]
+fn msrv_struct_fields() {
+    let test_msrv_struct = TestMsrvStruct::<3>::splat(String::from("old"));
+    assert_eq!(test_msrv_struct.field_one, ["old", "old", "old"]);
+    assert_eq!(test_msrv_struct.field_two.0, ["old", "old"]);
+
+    let test_msrv_struct = TestMsrvStruct::<1>::splat_with(|| String::from("new"));
+    assert_eq!(test_msrv_struct.field_one, ["new"]);
+}
+
+mod impl_trait {
+    pub trait UniformInit<T> {
+        fn splat(v: T) -> Self;
+    }
+}
+
+#[derive(Splat)]
+#[splat(impl_trait = "impl_trait::UniformInit<T>", alias = "broadcast")]
+struct TestImplTraitStruct<T> {
+    field_one: T,
+    field_two: T,
+}
+
+fn splat_generic<S: impl_trait::UniformInit<u32>>(v: u32) -> S {
+    S::splat(v)
+}
+
+#[test]
+fn impl_trait_struct_fields() {
+    let test_impl_trait_struct: TestImplTraitStruct<u32> = splat_generic(18);
+    assert_eq!(test_impl_trait_struct.field_one, 18);
+    assert_eq!(test_impl_trait_struct.field_two, 18);
+
+    let test_impl_trait_struct = TestImplTraitStruct::<u32>::broadcast(19);
+    assert_eq!(test_impl_trait_struct.field_one, 19);
+}
+
+#[derive(Splat)]
+#[splat(doc_alias = "broadcast, uniform")]
+struct TestDocAliasStruct {
+    field_one: u8,
+    field_two: u8,
+}
+
+#[test]
+fn doc_alias_struct_fields() {
+    let test_doc_alias_struct = TestDocAliasStruct::splat(20);
+    assert_eq!(test_doc_alias_struct.field_one, 20);
+    assert_eq!(test_doc_alias_struct.field_two, 20);
+}
+
+mod facade {
+    #[cfg(feature = "bumpalo")]
+    pub use bumpalo;
+    pub use splat_derive::Splat;
+}
+
+#[derive(facade::Splat)]
+#[splat(crate = "facade")]
+struct TestCrateStruct {
+    field_one: u8,
+    field_two: u8,
+}
+
+#[test]
+fn crate_struct_fields() {
+    let test_crate_struct = TestCrateStruct::splat(21);
+    assert_eq!(test_crate_struct.field_one, 21);
+    assert_eq!(test_crate_struct.field_two, 21);
+}
+
+#[derive(Splat)]
+#[splat(extra(fill))]
+struct TestSkipStruct {
+    field_one: f32,
+    #[splat(skip)]
+    id: u64,
+    field_two: f32,
+}
+
+#[test]
+fn skip_struct_fields() {
+    let mut test_skip_struct = TestSkipStruct::splat(0.5);
+    assert_eq!(test_skip_struct.field_one, 0.5);
+    assert_eq!(test_skip_struct.field_two, 0.5);
+    assert_eq!(test_skip_struct.id, 0);
+
+    test_skip_struct.id = 22;
+    test_skip_struct.fill(1.5);
+    assert_eq!(test_skip_struct.field_one, 1.5);
+    assert_eq!(test_skip_struct.id, 22);
+}
+
+const TEST_SKIP_WITH_LABEL: &str = "label";
+
+#[derive(Splat)]
+struct TestSkipWithStruct {
+    field_one: u8,
+    field_two: u8,
+    #[splat(skip_with = "TEST_SKIP_WITH_LABEL.to_uppercase()")]
+    label: String,
+    #[splat(skip_with = "vec![1, 2]")]
+    list: Vec<u8>,
+}
+
+#[test]
+fn skip_with_struct_fields() {
+    let test_skip_with_struct = TestSkipWithStruct::splat(23);
+    assert_eq!(test_skip_with_struct.field_one, 23);
+    assert_eq!(test_skip_with_struct.field_two, 23);
+    assert_eq!(test_skip_with_struct.label, "LABEL");
+    assert_eq!(test_skip_with_struct.list, [1, 2]);
+}
+
+fn test_with_negate(v: i16) -> i16 {
+    -v
+}
+
+fn test_with_ref_len(v: &str) -> usize {
+    v.len()
+}
+
+#[derive(Splat)]
+#[splat(extra(fill))]
+struct TestWithStruct {
+    field_one: String,
+    #[splat(with = "String::into_bytes")]
+    bytes: Vec<u8>,
+    #[splat(with_ref = "test_with_ref_len")]
+    len: usize,
+}
+
+#[derive(Splat)]
+struct TestWithCopyStruct {
+    field_one: i16,
+    #[splat(with = "test_with_negate")]
+    field_two: i16,
+}
+
+#[test]
+fn with_struct_fields() {
+    let mut test_with_struct = TestWithStruct::splat(String::from("with"));
+    assert_eq!(test_with_struct.field_one, "with");
+    assert_eq!(test_with_struct.bytes, b"with");
+    assert_eq!(test_with_struct.len, 4);
+
+    test_with_struct.fill(String::from("fill"));
+    assert_eq!(test_with_struct.bytes, b"fill");
+
+    let test_with_copy_struct = TestWithCopyStruct::splat(24);
+    assert_eq!(test_with_copy_struct.field_one, 24);
+    assert_eq!(test_with_copy_struct.field_two, -24);
+}
+
+#[derive(Splat)]
+struct TestCloneWithStruct {
+    field_one: std::rc::Rc<u8>,
+    #[splat(clone_with = "std::rc::Rc::clone")]
+    field_two: std::rc::Rc<u8>,
+    #[splat(clone_with = "test_clone_with_fresh")]
+    field_three: [std::rc::Rc<u8>; 2],
+}
+
+fn test_clone_with_fresh(v: &std::rc::Rc<u8>) -> std::rc::Rc<u8> {
+    std::rc::Rc::new(**v)
+}
+
+#[test]
+fn clone_with_struct_fields() {
+    let test_clone_with_struct = TestCloneWithStruct::splat(std::rc::Rc::new(25));
+    assert!(std::rc::Rc::ptr_eq(
+        &test_clone_with_struct.field_one,
+        &test_clone_with_struct.field_two
+    ));
+    assert_eq!(*test_clone_with_struct.field_three[0], 25);
+    assert_eq!(
+        std::rc::Rc::strong_count(&test_clone_with_struct.field_three[1]),
+        1
+    );
+}
+
+#[derive(Splat)]
+#[splat(by_ref)]
+struct TestByRefCloneWithStruct {
+    field_one: u32,
+    #[splat(clone_with = "test_clone_with_double")]
+    field_two: u32,
+}
+
+fn test_clone_with_double(v: &u32) -> u32 {
+    v * 2
+}
+
+#[test]
+fn by_ref_clone_with_struct_fields() {
+    let test_by_ref_clone_with_struct = TestByRefCloneWithStruct::splat(&131);
+    assert_eq!(test_by_ref_clone_with_struct.field_one, 131);
+    assert_eq!(test_by_ref_clone_with_struct.field_two, 262);
+}
+
+#[derive(Splat)]
+struct TestNestedStruct {
+    field_one: u16,
+    #[splat(nested)]
+    field_two: TestStruct,
+}
+
+#[test]
+fn nested_struct_fields() {
+    let test_nested_struct = TestNestedStruct::splat(26);
+    assert_eq!(test_nested_struct.field_one, 26);
+    assert_eq!(test_nested_struct.field_two.field_one, 26);
+    assert_eq!(test_nested_struct.field_two.field_two, 26);
+}
+
+#[derive(Splat)]
+struct TestFieldWrapStruct {
+    field_one: u8,
+    #[splat(wrap = "Box::new")]
+    field_two: Box<u8>,
+    #[splat(wrap = "std::rc::Rc::new, std::cell::Cell::new")]
+    field_three: std::rc::Rc<std::cell::Cell<u8>>,
+    #[splat(wrap = "Some")]
+    field_four: Option<[u8; 2]>,
+}
+
+#[test]
+fn field_wrap_struct_fields() {
+    let test_field_wrap_struct = TestFieldWrapStruct::splat(27);
+    assert_eq!(test_field_wrap_struct.field_one, 27);
+    assert_eq!(*test_field_wrap_struct.field_two, 27);
+    assert_eq!(test_field_wrap_struct.field_three.get(), 27);
+    assert_eq!(test_field_wrap_struct.field_four, Some([27; 2]));
+}
+
+#[derive(Splat)]
+#[splat(wrap = "std::sync::Arc::new")]
+struct TestWrapFieldOptionsStruct {
+    field_one: std::sync::Arc<u8>,
+    field_two: std::sync::Arc<u8>,
+    #[splat(skip)]
+    id: u32,
+    #[splat(with = "u32::from")]
+    field_three: u32,
+}
+
+#[test]
+fn wrap_field_options_struct_fields() {
+    let test_wrap_field_options_struct = TestWrapFieldOptionsStruct::splat(129);
+    assert_eq!(*test_wrap_field_options_struct.field_one, 129);
+    assert_eq!(*test_wrap_field_options_struct.field_two, 129);
+    assert_eq!(test_wrap_field_options_struct.id, 0);
+    assert_eq!(test_wrap_field_options_struct.field_three, 129);
+}
+
+#[derive(Splat)]
+struct TestPhantomStruct {
+    field_one: u32,
+    field_two: u32,
+    _marker: std::marker::PhantomData<Marker>,
+    _fn_marker: core::marker::PhantomData<fn() -> Marker>,
+}
+
+#[test]
+fn phantom_struct_fields() {
+    let test_phantom_struct = TestPhantomStruct::splat(28);
+    assert_eq!(test_phantom_struct.field_one, 28);
+    assert_eq!(test_phantom_struct.field_two, 28);
+}
+
+#[derive(Splat)]
+struct TestCastStruct {
+    field_one: u16,
+    #[splat(cast)]
+    field_two: u8,
+    #[splat(checked_cast)]
+    field_three: u32,
+    #[splat(cast)]
+    field_four: f32,
+}
+
+#[test]
+fn cast_struct_fields() {
+    let test_cast_struct = TestCastStruct::splat(300);
+    assert_eq!(test_cast_struct.field_one, 300);
+    assert_eq!(test_cast_struct.field_two, 44);
+    assert_eq!(test_cast_struct.field_three, 300);
+    assert_eq!(test_cast_struct.field_four, 300.0);
+}
+
+#[derive(Splat)]
+struct TestCheckedCastStruct {
+    field_one: u16,
+    #[splat(checked_cast)]
+    field_two: u8,
+}
+
+#[test]
+#[should_panic(expected = "splat value out of range for field `field_two`")]
+fn checked_cast_struct_fields() {
+    let test_checked_cast_struct = TestCheckedCastStruct::splat(200);
+    assert_eq!(test_checked_cast_struct.field_one, 200);
+    assert_eq!(test_checked_cast_struct.field_two, 200);
+
+    let _ = TestCheckedCastStruct::splat(300);
+}
+
+#[derive(Splat)]
+struct TestFieldIntoStruct {
+    field_one: u8,
+    #[splat(into)]
+    field_two: u64,
+    #[splat(into)]
+    field_three: f64,
+}
+
+#[test]
+fn field_into_struct_fields() {
+    let test_field_into_struct = TestFieldIntoStruct::splat(29);
+    assert_eq!(test_field_into_struct.field_one, 29);
+    assert_eq!(test_field_into_struct.field_two, 29);
+    assert_eq!(test_field_into_struct.field_three, 29.0);
+}
+
+#[derive(Splat)]
+#[splat(by_ref)]
+struct TestFieldIntoByRefStruct {
+    field_one: u8,
+    #[splat(into)]
+    field_two: u64,
+}
+
+#[test]
+fn field_into_by_ref_struct_fields() {
+    let test_field_into_by_ref_struct = TestFieldIntoByRefStruct::splat(&126);
+    assert_eq!(test_field_into_by_ref_struct.field_one, 126);
+    assert_eq!(test_field_into_by_ref_struct.field_two, 126);
+}
+
+#[derive(Splat)]
+#[splat(into)]
+struct TestIntoSkipStruct {
+    field_one: u64,
+    field_two: u64,
+    #[splat(skip)]
+    id: u32,
+}
+
+#[test]
+fn into_skip_struct_fields() {
+    let test_into_skip_struct = TestIntoSkipStruct::splat(127u8);
+    assert_eq!(test_into_skip_struct.field_one, 127);
+    assert_eq!(test_into_skip_struct.field_two, 127);
+    assert_eq!(test_into_skip_struct.id, 0);
+}
+
+#[derive(Splat)]
+struct TestShareStruct {
+    #[splat(share)]
+    field_one: std::sync::Arc<String>,
+    #[splat(share)]
+    field_two: std::sync::Arc<String>,
+}
+
+#[test]
+fn share_struct_fields() {
+    let test_share_struct = TestShareStruct::splat(std::sync::Arc::new(String::from("share")));
+    assert_eq!(*test_share_struct.field_one, "share");
+    assert!(std::sync::Arc::ptr_eq(
+        &test_share_struct.field_one,
+        &test_share_struct.field_two
+    ));
+}
+
+#[derive(Splat)]
+#[splat(into)]
+struct TestIntoShareStruct {
+    #[splat(share)]
+    field_one: std::rc::Rc<u8>,
+    #[splat(share)]
+    field_two: std::rc::Rc<u8>,
+}
+
+#[test]
+fn into_share_struct_fields() {
+    let test_into_share_struct = TestIntoShareStruct::splat(128);
+    assert_eq!(*test_into_share_struct.field_one, 128);
+    assert!(std::rc::Rc::ptr_eq(
+        &test_into_share_struct.field_one,
+        &test_into_share_struct.field_two
+    ));
+}
+
+#[derive(Splat)]
+#[splat(extra(fill))]
+struct TestFieldCfgStruct {
+    field_one: u8,
+    #[cfg(any())]
+    field_two: u8,
+    #[cfg(test)]
+    field_three: u8,
+}
+
+#[test]
+fn field_cfg_struct_fields() {
+    let mut test_field_cfg_struct = TestFieldCfgStruct::splat(30);
+    assert_eq!(test_field_cfg_struct.field_one, 30);
+    assert_eq!(test_field_cfg_struct.field_three, 30);
+
+    test_field_cfg_struct.fill(31);
+    assert_eq!(test_field_cfg_struct.field_three, 31);
+}
+
+#[derive(Splat)]
+#[cfg_attr(test, splat(extra(fill)))]
+struct TestCfgAttrStruct {
+    field_one: u8,
+    #[cfg_attr(test, splat(skip))]
+    field_two: u16,
+    #[cfg_attr(any(), splat(skip))]
+    field_three: u8,
+}
+
+#[test]
+fn cfg_attr_struct_fields() {
+    let mut test_cfg_attr_struct = TestCfgAttrStruct::splat(32);
+    assert_eq!(test_cfg_attr_struct.field_one, 32);
+    assert_eq!(test_cfg_attr_struct.field_two, 0);
+    assert_eq!(test_cfg_attr_struct.field_three, 32);
+
+    test_cfg_attr_struct.fill(33);
+    assert_eq!(test_cfg_attr_struct.field_three, 33);
+}
+
+#[derive(Splat)]
+struct TestAutoWrapStruct {
+    field_one: Box<std::cell::RefCell<u8>>,
+    field_two: [Box<std::cell::RefCell<u8>>; 2],
+}
+
+#[derive(Splat)]
+struct TestAutoWrapBoxStruct {
+    field_one: Box<u8>,
+    field_two: Box<u8>,
+}
+
+#[derive(Splat)]
+#[splat(wrap = "")]
+struct TestNoAutoWrapStruct {
+    field_one: Box<u8>,
+    field_two: Box<u8>,
+}
+
+struct NotCloneConfig(u8);
+
+#[derive(Splat)]
+struct TestSharedPointerStruct {
+    field_one: std::rc::Rc<NotCloneConfig>,
+    field_two: std::rc::Rc<NotCloneConfig>,
+}
+
+#[test]
+fn auto_wrap_struct_fields() {
+    let test_auto_wrap_struct = TestAutoWrapStruct::splat(34);
+    assert_eq!(*test_auto_wrap_struct.field_one.borrow(), 34);
+    assert_eq!(*test_auto_wrap_struct.field_two[1].borrow(), 34);
+
+    let test_auto_wrap_box_struct = TestAutoWrapBoxStruct::splat(34);
+    assert_eq!(*test_auto_wrap_box_struct.field_one, 34);
+    assert_eq!(*test_auto_wrap_box_struct.field_two, 34);
+
+    let test_no_auto_wrap_struct = TestNoAutoWrapStruct::splat(Box::new(35));
+    assert_eq!(*test_no_auto_wrap_struct.field_one, 35);
+    assert_eq!(*test_no_auto_wrap_struct.field_two, 35);
+
+    let test_shared_pointer_struct =
+        TestSharedPointerStruct::splat(std::rc::Rc::new(NotCloneConfig(130)));
+    assert_eq!(test_shared_pointer_struct.field_one.0, 130);
+    assert!(std::rc::Rc::ptr_eq(
+        &test_shared_pointer_struct.field_one,
+        &test_shared_pointer_struct.field_two
+    ));
+}
+
+#[derive(Splat)]
+struct TestAtomicStruct {
+    field_one: std::sync::atomic::AtomicBool,
+    field_two: std::sync::atomic::AtomicBool,
+}
+
+#[derive(Splat)]
+#[splat(extra(set_all))]
+struct TestAtomicArrayStruct {
+    field_one: [std::sync::atomic::AtomicU64; 2],
+    field_two: std::sync::atomic::AtomicU64,
+}
+
+#[test]
+fn atomic_struct_fields() {
+    use std::sync::atomic::Ordering;
+
+    let test_atomic_struct = TestAtomicStruct::splat(true);
+    assert!(test_atomic_struct.field_one.load(Ordering::Relaxed));
+    assert!(test_atomic_struct.field_two.load(Ordering::Relaxed));
+
+    let test_atomic_array_struct = TestAtomicArrayStruct::splat(36);
+    assert_eq!(
+        test_atomic_array_struct.field_one[1].load(Ordering::Relaxed),
+        36
+    );
+    assert_eq!(
+        test_atomic_array_struct.field_two.load(Ordering::Relaxed),
+        36
+    );
+}
+
+#[derive(Splat)]
+#[splat(extra(set_all))]
+struct TestLockStruct {
+    field_one: std::sync::Mutex<Vec<u8>>,
+    field_two: std::sync::Mutex<Vec<u8>>,
+}
+
+#[derive(Splat)]
+struct TestSharedLockStruct {
+    field_one: std::sync::Arc<std::sync::RwLock<u8>>,
+    field_two: std::sync::Arc<std::sync::RwLock<u8>>,
+}
+
+#[test]
+fn lock_struct_fields() {
+    let test_lock_struct = TestLockStruct::splat(vec![37]);
+    test_lock_struct.field_one.lock().unwrap().push(38);
+    assert_eq!(*test_lock_struct.field_one.lock().unwrap(), [37, 38]);
+    assert_eq!(*test_lock_struct.field_two.lock().unwrap(), [37]);
+
+    let test_shared_lock_struct =
+        TestSharedLockStruct::splat(std::sync::Arc::new(std::sync::RwLock::new(39)));
+    *test_shared_lock_struct.field_one.write().unwrap() = 40;
+    assert_eq!(*test_shared_lock_struct.field_two.read().unwrap(), 40);
+}
+
+#[derive(Splat)]
+struct TestOptionStruct {
+    field_one: Option<String>,
+    field_two: Option<String>,
+}
+
+#[test]
+fn option_struct_fields() {
+    let test_option_struct = TestOptionStruct::splat(None);
+    assert_eq!(test_option_struct.field_one, None);
+
+    let test_option_struct = TestOptionStruct::splat_some(String::from("some"));
+    assert_eq!(test_option_struct.field_one.as_deref(), Some("some"));
+    assert_eq!(test_option_struct.field_two.as_deref(), Some("some"));
+
+    let test_option_struct = TestOptionStruct::splat_none();
+    assert_eq!(test_option_struct.field_two, None);
+}
+
+#[derive(Splat)]
+struct TestNonZeroStruct {
+    field_one: std::num::NonZeroU32,
+    field_two: [std::num::NonZeroU32; 2],
+}
+
+#[test]
+fn non_zero_struct_fields() {
+    let test_non_zero_struct = TestNonZeroStruct::try_splat(40).unwrap();
+    assert_eq!(test_non_zero_struct.field_one.get(), 40);
+    assert_eq!(test_non_zero_struct.field_two[1].get(), 40);
+
+    assert!(TestNonZeroStruct::try_splat(0).is_none());
+}
+
+#[derive(Splat)]
+#[splat(by_ref)]
+struct TestByRefNonZeroStruct {
+    field_one: std::num::NonZeroU32,
+    field_two: [std::num::NonZeroU32; 2],
+}
+
+#[test]
+fn by_ref_non_zero_struct_fields() {
+    let value = std::num::NonZeroU32::new(114).unwrap();
+    let test_by_ref_non_zero_struct = TestByRefNonZeroStruct::splat(&value);
+    assert_eq!(test_by_ref_non_zero_struct.field_one, value);
+
+    let test_by_ref_non_zero_struct = TestByRefNonZeroStruct::try_splat(115).unwrap();
+    assert_eq!(test_by_ref_non_zero_struct.field_one.get(), 115);
+    assert_eq!(test_by_ref_non_zero_struct.field_two[1].get(), 115);
+    assert!(TestByRefNonZeroStruct::try_splat(0).is_none());
+}
+
+#[derive(Splat)]
+struct TestCowStruct<'a> {
+    field_one: std::borrow::Cow<'a, str>,
+    field_two: std::borrow::Cow<'a, str>,
+}
+
+#[derive(Splat)]
+struct TestCowSliceStruct<'a, T: Clone> {
+    field_one: std::borrow::Cow<'a, [T]>,
+    field_two: std::borrow::Cow<'a, [T]>,
+}
+
+#[test]
+fn cow_struct_fields() {
+    let borrowed = String::from("borrowed");
+    let test_cow_struct = TestCowStruct::splat(borrowed.as_str());
+    assert!(matches!(
+        test_cow_struct.field_one,
+        std::borrow::Cow::Borrowed("borrowed")
+    ));
+    assert_eq!(test_cow_struct.field_two, "borrowed");
+
+    let test_cow_struct = TestCowStruct::splat(String::from("owned"));
+    assert!(matches!(
+        test_cow_struct.field_one,
+        std::borrow::Cow::Owned(_)
+    ));
+    assert_eq!(test_cow_struct.field_two, "owned");
+
+    let test_cow_slice_struct = TestCowSliceStruct::splat(vec![41, 42]);
+    assert_eq!(*test_cow_slice_struct.field_one, [41, 42]);
+    let test_cow_slice_struct = TestCowSliceStruct::splat(&[43][..]);
+    assert_eq!(*test_cow_slice_struct.field_two, [43]);
+}
+
+#[derive(Splat)]
+struct TestCollectionStruct {
+    field_one: Vec<u8>,
+    field_two: Vec<u8>,
+}
+
+#[derive(Splat)]
+struct TestBoxedSliceStruct {
+    field_one: Box<[String]>,
+    field_two: Box<[String]>,
+}
+
+#[test]
+fn collection_struct_fields() {
+    let test_collection_struct = TestCollectionStruct::splat_n(44, 3);
+    assert_eq!(test_collection_struct.field_one, [44; 3]);
+    assert_eq!(test_collection_struct.field_two, [44; 3]);
+
+    let test_boxed_slice_struct = TestBoxedSliceStruct::splat_n(String::from("n"), 2);
+    assert_eq!(*test_boxed_slice_struct.field_one, ["n", "n"]);
+
+    let test_boxed_slice_struct = TestBoxedSliceStruct::splat(Box::new([String::from("box")]));
+    assert_eq!(*test_boxed_slice_struct.field_two, ["box"]);
+}
+
+#[derive(Splat)]
+struct TestOnceCellStruct {
+    field_one: std::cell::OnceCell<String>,
+    field_two: std::cell::OnceCell<String>,
+}
+
+#[derive(Splat)]
+struct TestOnceLockStruct {
+    field_one: std::sync::OnceLock<u8>,
+    field_two: std::sync::OnceLock<u8>,
+}
+
+#[test]
+fn once_cell_struct_fields() {
+    let test_once_cell_struct = TestOnceCellStruct::splat_initialized(String::from("once"));
+    assert_eq!(test_once_cell_struct.field_one.get().unwrap(), "once");
+    assert_eq!(test_once_cell_struct.field_two.get().unwrap(), "once");
+
+    let test_once_cell_struct = TestOnceCellStruct::splat_empty();
+    assert!(test_once_cell_struct.field_one.get().is_none());
+
+    let test_once_lock_struct = TestOnceLockStruct::splat_initialized(45);
+    assert_eq!(test_once_lock_struct.field_two.get(), Some(&45));
+    let test_once_lock_struct = TestOnceLockStruct::splat_empty();
+    assert!(test_once_lock_struct.field_one.get().is_none());
+}
+
+#[derive(Splat)]
+#[splat(extra(set_all))]
+struct TestSetAllStruct {
+    field_one: std::cell::Cell<u8>,
+    field_two: [std::cell::Cell<u8>; 2],
+    field_three: (std::cell::Cell<u8>, std::cell::Cell<u8>),
+}
+
+#[test]
+fn set_all_struct_fields() {
+    let test_set_all_struct = TestSetAllStruct::splat(46);
+    test_set_all_struct.set_all(47);
+    assert_eq!(test_set_all_struct.field_one.get(), 47);
+    assert_eq!(test_set_all_struct.field_two[1].get(), 47);
+    assert_eq!(test_set_all_struct.field_three.1.get(), 47);
+
+    let test_atomic_array_struct = TestAtomicArrayStruct::splat(48);
+    test_atomic_array_struct.set_all(49);
+    assert_eq!(
+        test_atomic_array_struct.field_one[0].load(std::sync::atomic::Ordering::Relaxed),
+        49
+    );
+
+    let test_lock_struct = TestLockStruct::splat(vec![50]);
+    test_lock_struct.set_all(vec![51]);
+    assert_eq!(*test_lock_struct.field_two.lock().unwrap(), [51]);
+}
+
+#[derive(Splat)]
+#[splat(by_ref, extra(set_all))]
+struct TestByRefSetAllStruct {
+    field_one: std::cell::Cell<u8>,
+    field_two: [std::cell::Cell<u8>; 2],
+}
+
+#[derive(Splat)]
+#[splat(by_ref, extra(set_all))]
+struct TestByRefLockStruct {
+    field_one: std::sync::Mutex<String>,
+    field_two: std::sync::Mutex<String>,
+}
+
+#[derive(Splat)]
+#[splat(clamp = "..=100", extra(set_all))]
+struct TestClampSetAllStruct {
+    field_one: std::cell::Cell<u8>,
+    field_two: std::cell::Cell<u8>,
+}
+
+#[test]
+fn by_ref_set_all_struct_fields() {
+    let test_by_ref_set_all_struct = TestByRefSetAllStruct::splat(&116);
+    test_by_ref_set_all_struct.set_all(&117);
+    assert_eq!(test_by_ref_set_all_struct.field_one.get(), 117);
+    assert_eq!(test_by_ref_set_all_struct.field_two[1].get(), 117);
+
+    let value = String::from("lock");
+    let test_by_ref_lock_struct = TestByRefLockStruct::splat(&value);
+    test_by_ref_lock_struct.set_all(&String::from("set"));
+    assert_eq!(*test_by_ref_lock_struct.field_one.lock().unwrap(), "set");
+    assert_eq!(*test_by_ref_lock_struct.field_two.lock().unwrap(), "set");
+
+    let test_clamp_set_all_struct = TestClampSetAllStruct::splat(0);
+    test_clamp_set_all_struct.set_all(118);
+    assert_eq!(test_clamp_set_all_struct.field_one.get(), 100);
+    assert_eq!(test_clamp_set_all_struct.field_two.get(), 100);
+}
+
+#[derive(Splat)]
+#[splat(extra(fill, splat_with))]
+#[repr(C, packed)]
+struct TestPackedStruct {
+    #[splat(cast)]
+    field_one: u8,
+    field_two: u32,
+    field_three: [u32; 2],
+}
+
+#[derive(Splat)]
+#[repr(C, packed(2))]
+struct TestPackedCellStruct {
+    field_one: std::cell::Cell<u32>,
+    field_two: std::cell::Cell<u32>,
+}
+
+#[test]
+fn packed_struct_fields() {
+    let mut test_packed_struct = TestPackedStruct::splat(52);
+    assert_eq!({ test_packed_struct.field_one }, 52);
+    assert_eq!({ test_packed_struct.field_two }, 52);
+
+    test_packed_struct.fill(53);
+    assert_eq!({ test_packed_struct.field_three }, [53; 2]);
+
+    let test_packed_struct = TestPackedStruct::splat_with(|| 54);
+    assert_eq!({ test_packed_struct.field_two }, 54);
+
+    let test_packed_cell_struct = TestPackedCellStruct::splat(55);
+    assert_eq!({ test_packed_cell_struct.field_one }.get(), 55);
+    assert_eq!({ test_packed_cell_struct.field_two }.get(), 55);
+}
+
+#[derive(Splat)]
+#[splat(extra(fill), into, clamp = "0..=100")]
+struct TestFillIntoStruct {
+    field_one: u32,
+    field_two: u32,
+}
+
+#[derive(Splat)]
+#[splat(extra(fill), by_ref)]
+struct TestFillByRefStruct {
+    field_one: String,
+    field_two: String,
+}
+
+#[test]
+fn fill_input_struct_fields() {
+    let mut test_fill_into_struct = TestFillIntoStruct::splat(56u8);
+    test_fill_into_struct.fill(57u16);
+    assert_eq!(test_fill_into_struct.field_one, 57);
+    test_fill_into_struct.fill(1000u32);
+    assert_eq!(test_fill_into_struct.field_two, 100);
+
+    let fill = String::from("fill");
+    let mut test_fill_by_ref_struct = TestFillByRefStruct::splat(&fill);
+    test_fill_by_ref_struct.fill(&String::from("by_ref"));
+    assert_eq!(test_fill_by_ref_struct.field_one, "by_ref");
+    assert_eq!(test_fill_by_ref_struct.field_two, "by_ref");
+}
+
+#[derive(Splat)]
+#[splat(extra(fill))]
+struct TestCloneFromStruct {
+    field_one: Vec<u8>,
+    field_two: Vec<u8>,
+}
+
+#[test]
+fn clone_from_struct_fields() {
+    let mut test_clone_from_struct = TestCloneFromStruct::splat(vec![0; 8]);
+    let buffer = test_clone_from_struct.field_one.as_ptr();
+    test_clone_from_struct.fill(vec![58; 4]);
+    assert_eq!(test_clone_from_struct.field_one, [58; 4]);
+    assert_eq!(test_clone_from_struct.field_two, [58; 4]);
+    assert_eq!(test_clone_from_struct.field_one.as_ptr(), buffer);
+}
+
+#[derive(Splat)]
+#[splat(extra(update_all))]
+struct TestUpdateAllStruct {
+    field_one: u32,
+    field_two: [u32; 2],
+    field_three: (u32, [u32; 1]),
+    #[splat(cast)]
+    field_four: u8,
+}
+
+#[derive(Splat)]
+#[splat(extra(update_all))]
+#[repr(C, packed)]
+struct TestPackedUpdateAllStruct {
+    field_one: u16,
+    field_two: [u16; 2],
+}
+
+#[test]
+fn update_all_struct_fields() {
+    let mut test_update_all_struct = TestUpdateAllStruct::splat(59);
+    test_update_all_struct.update_all(|v| *v *= 2);
+    assert_eq!(test_update_all_struct.field_one, 118);
+    assert_eq!(test_update_all_struct.field_two, [118; 2]);
+    assert_eq!(test_update_all_struct.field_three, (118, [118]));
+    assert_eq!(test_update_all_struct.field_four, 59);
+
+    let mut test_packed_update_all_struct = TestPackedUpdateAllStruct::splat(60);
+    test_packed_update_all_struct.update_all(|v| *v += 1);
+    assert_eq!({ test_packed_update_all_struct.field_one }, 61);
+    assert_eq!({ test_packed_update_all_struct.field_two }, [61; 2]);
+}
+
+#[derive(Splat)]
+#[splat(extra(replace_all))]
+struct TestReplaceAllStruct {
+    field_one: String,
+    field_two: String,
+}
+
+#[test]
+fn replace_all_struct_fields() {
+    let mut test_replace_all_struct = TestReplaceAllStruct::splat(String::from("front"));
+    let previous = test_replace_all_struct.replace_all(String::from("back"));
+    assert_eq!(previous.field_one, "front");
+    assert_eq!(previous.field_two, "front");
+    assert_eq!(test_replace_all_struct.field_one, "back");
+    assert_eq!(test_replace_all_struct.field_two, "back");
+}
+
+#[derive(Splat)]
+#[splat(extra(with_all))]
+struct TestWithAllStruct {
+    field_one: u8,
+    field_two: u8,
+    #[splat(skip)]
+    id: u64,
+}
+
+#[test]
+fn with_all_struct_fields() {
+    let mut test_with_all_struct = TestWithAllStruct::splat(61);
+    test_with_all_struct.id = 62;
+    let test_with_all_struct = test_with_all_struct.with_all(63);
+    assert_eq!(test_with_all_struct.field_one, 63);
+    assert_eq!(test_with_all_struct.field_two, 63);
+    assert_eq!(test_with_all_struct.id, 62);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_with))]
+struct TestNotCloneStruct {
+    field_one: NotClone,
+    field_two: NotClone,
+}
+
+#[test]
+fn not_clone_struct_fields() {
+    let mut next = 63;
+    let test_not_clone_struct = TestNotCloneStruct::splat_with(|| {
+        next += 1;
+        NotClone(next)
+    });
+    assert_eq!(test_not_clone_struct.field_one.0, 64);
+    assert_eq!(test_not_clone_struct.field_two.0, 65);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_with_index))]
+struct TestSplatWithIndexStruct {
+    field_one: usize,
+    #[splat(skip)]
+    field_two: u8,
+    field_three: [usize; 2],
+}
+
+#[test]
+fn splat_with_index_struct_fields() {
+    let test_splat_with_index_struct = TestSplatWithIndexStruct::splat_with_index(|i| i + 66);
+    assert_eq!(test_splat_with_index_struct.field_one, 66);
+    assert_eq!(test_splat_with_index_struct.field_two, 0);
+    assert_eq!(test_splat_with_index_struct.field_three, [68; 2]);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_with_name))]
+struct TestSplatWithNameStruct {
+    host: String,
+    r#type: String,
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_with_name))]
+struct TestSplatWithNameTupleStruct(String, String);
+
+#[test]
+fn splat_with_name_struct_fields() {
+    let test_splat_with_name_struct = TestSplatWithNameStruct::splat_with_name(String::from);
+    assert_eq!(test_splat_with_name_struct.host, "host");
+    assert_eq!(test_splat_with_name_struct.r#type, "type");
+
+    let test_splat_with_name_tuple_struct =
+        TestSplatWithNameTupleStruct::splat_with_name(String::from);
+    assert_eq!(test_splat_with_name_tuple_struct.0, "0");
+    assert_eq!(test_splat_with_name_tuple_struct.1, "1");
+}
+
+#[derive(Splat)]
+#[splat(extra(try_splat_with))]
+struct TestTrySplatWithStruct {
+    field_one: u8,
+    field_two: u8,
+    field_three: u8,
+}
+
+#[test]
+fn try_splat_with_struct_fields() {
+    let mut inputs = ["69", "70", "71"].into_iter();
+    let test_try_splat_with_struct =
+        TestTrySplatWithStruct::try_splat_with(|| inputs.next().unwrap().parse()).unwrap();
+    assert_eq!(test_try_splat_with_struct.field_one, 69);
+    assert_eq!(test_try_splat_with_struct.field_two, 70);
+    assert_eq!(test_try_splat_with_struct.field_three, 71);
+
+    let mut calls = 0;
+    let result = TestTrySplatWithStruct::try_splat_with(|| {
+        calls += 1;
+        "x".parse::<u8>()
+    });
+    assert!(result.is_err());
+    assert_eq!(calls, 1);
+}
+
+#[derive(Splat)]
+#[splat(extra(try_splat_with))]
+struct TestArrayTrySplatWithStruct {
+    field_one: f32,
+    field_two: [f32; 2],
+    field_three: ([f32; 1], f32),
+}
+
+#[test]
+fn array_try_splat_with_struct_fields() {
+    let mut inputs = ["119", "120", "121", "122", "123"].into_iter();
+    let test_array_try_splat_with_struct =
+        TestArrayTrySplatWithStruct::try_splat_with(|| inputs.next().unwrap().parse()).unwrap();
+    assert_eq!(test_array_try_splat_with_struct.field_one, 119.0);
+    assert_eq!(test_array_try_splat_with_struct.field_two, [120.0, 121.0]);
+    assert_eq!(
+        test_array_try_splat_with_struct.field_three,
+        ([122.0], 123.0)
+    );
+
+    let mut inputs = ["124", "x", "125"].into_iter();
+    let mut calls = 0;
+    let result = TestArrayTrySplatWithStruct::try_splat_with(|| {
+        calls += 1;
+        inputs.next().unwrap().parse::<f32>()
+    });
+    assert!(result.is_err());
+    assert_eq!(calls, 2);
+}
+
+struct ZeroSized;
+
+#[derive(Splat)]
+#[splat(extra(try_splat_with))]
+struct TestZeroSizedTrySplatWithStruct {
+    field_one: [ZeroSized; 2],
+    field_two: ZeroSized,
+}
+
+#[test]
+fn zero_sized_try_splat_with_struct_fields() {
+    let mut calls = 0;
+    let result = TestZeroSizedTrySplatWithStruct::try_splat_with(|| {
+        calls += 1;
+        if calls > 10 {
+            Err(calls)
+        } else {
+            Ok(ZeroSized)
+        }
+    });
+    let test_zero_sized_try_splat_with_struct = result.unwrap();
+    assert_eq!(test_zero_sized_try_splat_with_struct.field_one.len(), 2);
+    let ZeroSized = test_zero_sized_try_splat_with_struct.field_two;
+    assert_eq!(calls, 3);
+}
+
+#[derive(Default)]
+struct DefaultNotClone(u8);
+
+#[derive(Splat)]
+#[splat(extra(splat_default))]
+struct TestDefaultNotCloneStruct {
+    field_one: DefaultNotClone,
+    field_two: DefaultNotClone,
+}
+
+#[test]
+fn default_not_clone_struct_fields() {
+    let test_default_not_clone_struct = TestDefaultNotCloneStruct::splat_default();
+    assert_eq!(test_default_not_clone_struct.field_one.0, 0);
+    assert_eq!(test_default_not_clone_struct.field_two.0, 0);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_boxed))]
+struct TestSplatBoxedStruct {
+    field_one: String,
+    field_two: [String; 64],
+    #[splat(skip)]
+    id: u64,
+    _marker: std::marker::PhantomData<Marker>,
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_boxed))]
+#[repr(C, packed)]
+struct TestPackedSplatBoxedStruct {
+    field_one: u8,
+    field_two: [u8; 1024],
+}
+
+#[test]
+fn splat_boxed_struct_fields() {
+    let test_splat_boxed_struct = TestSplatBoxedStruct::splat_boxed(String::from("boxed"));
+    assert_eq!(test_splat_boxed_struct.field_one, "boxed");
+    assert!(test_splat_boxed_struct
+        .field_two
+        .iter()
+        .all(|field| field == "boxed"));
+    assert_eq!(test_splat_boxed_struct.id, 0);
+
+    let test_packed_splat_boxed_struct = TestPackedSplatBoxedStruct::splat_boxed(72);
+    assert_eq!({ test_packed_splat_boxed_struct.field_one }, 72);
+    assert_eq!({ test_packed_splat_boxed_struct.field_two }, [72; 1024]);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_uninit))]
+struct TestSplatUninitStruct {
+    field_one: u32,
+    field_two: [u32; 16],
+    #[splat(wrap = "Some")]
+    field_three: Option<u32>,
+}
+
+#[test]
+fn splat_uninit_struct_fields() {
+    let mut dest = std::mem::MaybeUninit::uninit();
+    let test_splat_uninit_struct = TestSplatUninitStruct::splat_uninit(&mut dest, 73);
+    assert_eq!(test_splat_uninit_struct.field_one, 73);
+    assert_eq!(test_splat_uninit_struct.field_two, [73; 16]);
+    assert_eq!(test_splat_uninit_struct.field_three, Some(73));
+
+    test_splat_uninit_struct.field_one = 74;
+    let test_splat_uninit_struct = unsafe { dest.assume_init() };
+    assert_eq!(test_splat_uninit_struct.field_one, 74);
+}
+
+#[cfg(feature = "bumpalo")]
+#[derive(Splat)]
+#[splat(extra(splat_in))]
+struct TestSplatInStruct {
+    field_one: String,
+    field_two: [String; 2],
+}
+
+#[cfg(feature = "bumpalo")]
+#[derive(facade::Splat)]
+#[splat(crate = "facade", extra(splat_in))]
+struct TestCrateSplatInStruct {
+    field_one: u32,
+    field_two: [u32; 4],
+}
+
+#[cfg(feature = "bumpalo")]
+#[test]
+fn splat_in_struct_fields() {
+    let arena = bumpalo::Bump::new();
+    let test_splat_in_struct = TestSplatInStruct::splat_in(&arena, String::from("arena"));
+    assert_eq!(test_splat_in_struct.field_one, "arena");
+    assert_eq!(test_splat_in_struct.field_two, ["arena", "arena"]);
+
+    test_splat_in_struct.field_one.push_str("132");
+    assert_eq!(test_splat_in_struct.field_one, "arena132");
+
+    let test_crate_splat_in_struct = TestCrateSplatInStruct::splat_in(&arena, 133);
+    assert_eq!(test_crate_splat_in_struct.field_one, 133);
+    assert_eq!(test_crate_splat_in_struct.field_two, [133; 4]);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_many))]
+struct TestSplatManyStruct {
+    field_one: String,
+    field_two: String,
+}
+
+#[test]
+fn splat_many_struct_fields() {
+    let test_splat_many_structs = TestSplatManyStruct::splat_many(String::from("many"), 3);
+    assert_eq!(test_splat_many_structs.len(), 3);
+    for test_splat_many_struct in &test_splat_many_structs {
+        assert_eq!(test_splat_many_struct.field_one, "many");
+        assert_eq!(test_splat_many_struct.field_two, "many");
+    }
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_seq))]
+struct TestSplatSeqStruct {
+    field_one: u8,
+    field_two: [u8; 3],
+    field_three: (u8, u8),
+}
+
+#[test]
+fn splat_seq_struct_fields() {
+    let test_splat_seq_struct = TestSplatSeqStruct::splat_seq(76, 2);
+    assert_eq!(test_splat_seq_struct.field_one, 76);
+    assert_eq!(test_splat_seq_struct.field_two, [78, 80, 82]);
+    assert_eq!(test_splat_seq_struct.field_three, (84, 86));
+
+    let test_splat_seq_struct = TestSplatSeqStruct::splat_seq(250, 1);
+    assert_eq!(test_splat_seq_struct.field_three, (254, u8::MAX));
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_gradient))]
+struct TestSplatGradientStruct {
+    field_one: f64,
+    field_two: [f64; 3],
+    #[splat(skip)]
+    id: u64,
+    field_three: f64,
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_gradient))]
+struct TestSingleSplatGradientStruct {
+    field_one: f32,
+    field_two: [f32; 0],
+}
+
+#[test]
+fn splat_gradient_struct_fields() {
+    let test_splat_gradient_struct = TestSplatGradientStruct::splat_gradient(77.0, 81.0);
+    assert_eq!(test_splat_gradient_struct.field_one, 77.0);
+    assert_eq!(test_splat_gradient_struct.field_two, [78.0, 79.0, 80.0]);
+    assert_eq!(test_splat_gradient_struct.id, 0);
+    assert_eq!(test_splat_gradient_struct.field_three, 81.0);
+
+    let test_single_splat_gradient_struct =
+        TestSingleSplatGradientStruct::splat_gradient(82.0, 83.0);
+    assert_eq!(test_single_splat_gradient_struct.field_one, 82.0);
+    assert_eq!(test_single_splat_gradient_struct.field_two, []);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_cycle))]
+struct TestSplatCycleStruct {
+    field_one: NotClone,
+    field_two: [NotClone; 3],
+    field_three: NotClone,
+}
+
+#[test]
+fn splat_cycle_struct_fields() {
+    let values = [84, 85];
+    let test_splat_cycle_struct =
+        TestSplatCycleStruct::splat_cycle(values.iter().map(|&value| NotClone(value)));
+    assert_eq!(test_splat_cycle_struct.field_one.0, 84);
+    assert_eq!(
+        test_splat_cycle_struct.field_two.map(|field| field.0),
+        [85, 84, 85]
+    );
+    assert_eq!(test_splat_cycle_struct.field_three.0, 84);
+}
+
+#[derive(Splat)]
+#[splat(extra(copy_from_slice))]
+struct TestCopyFromSliceStruct {
+    field_one: u8,
+    field_two: [u8; 2],
+    #[splat(skip_with = "86")]
+    id: u8,
+    field_three: (u8, u8),
+}
+
+#[test]
+fn copy_from_slice_struct_fields() {
+    let mut test_copy_from_slice_struct = TestCopyFromSliceStruct::splat(0);
+    test_copy_from_slice_struct.copy_from_slice(&[87, 88, 89, 90, 91]);
+    assert_eq!(test_copy_from_slice_struct.field_one, 87);
+    assert_eq!(test_copy_from_slice_struct.field_two, [88, 89]);
+    assert_eq!(test_copy_from_slice_struct.id, 86);
+    assert_eq!(test_copy_from_slice_struct.field_three, (90, 91));
+
+    assert_eq!(
+        test_copy_from_slice_struct.try_copy_from_slice(&[92, 93]),
+        Err(5)
+    );
+    assert_eq!(test_copy_from_slice_struct.field_one, 87);
+}
+
+#[test]
+#[should_panic(expected = "source slice length (1) does not match the number of values (5)")]
+fn copy_from_slice_struct_length_mismatch() {
+    TestCopyFromSliceStruct::splat(0).copy_from_slice(&[94]);
+}
+
+#[derive(Splat)]
+#[splat(extra(try_splat_parse))]
+struct TestTrySplatParseStruct {
+    field_one: u16,
+    field_two: [u16; 2],
+}
+
+#[test]
+fn try_splat_parse_struct_fields() {
+    let test_try_splat_parse_struct = TestTrySplatParseStruct::try_splat_parse("95").unwrap();
+    assert_eq!(test_try_splat_parse_struct.field_one, 95);
+    assert_eq!(test_try_splat_parse_struct.field_two, [95, 95]);
+
+    assert!(TestTrySplatParseStruct::try_splat_parse("-96").is_err());
+}
+
+#[derive(Splat)]
+#[splat(extra(is_splat))]
+struct TestIsSplatStruct {
+    field_one: String,
+    field_two: [String; 2],
+    #[splat(skip)]
+    id: u64,
+}
+
+#[derive(Splat)]
+#[splat(extra(is_splat))]
+#[repr(C, packed)]
+struct TestPackedIsSplatStruct {
+    field_one: u32,
+    field_two: (u32, u32),
+}
+
+#[test]
+fn is_splat_struct_fields() {
+    let mut test_is_splat_struct = TestIsSplatStruct::splat(String::from("equal"));
+    test_is_splat_struct.id = 97;
+    assert!(test_is_splat_struct.is_splat());
+    test_is_splat_struct.field_two[1].push('!');
+    assert!(!test_is_splat_struct.is_splat());
+
+    let mut test_packed_is_splat_struct = TestPackedIsSplatStruct::splat(98);
+    assert!(test_packed_is_splat_struct.is_splat());
+    test_packed_is_splat_struct.field_one = 99;
+    assert!(!test_packed_is_splat_struct.is_splat());
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_value))]
+struct TestSplatValueStruct {
+    field_one: String,
+    field_two: (String, String),
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_value))]
+#[repr(C, packed)]
+struct TestPackedSplatValueStruct {
+    field_one: u32,
+    field_two: [u32; 2],
+}
+
+#[test]
+fn splat_value_struct_fields() {
+    let mut test_splat_value_struct = TestSplatValueStruct::splat(String::from("collapse"));
+    assert_eq!(
+        test_splat_value_struct.splat_value().map(String::as_str),
+        Some("collapse")
+    );
+    test_splat_value_struct.field_two.0.clear();
+    assert_eq!(test_splat_value_struct.splat_value(), None);
+    assert_eq!(test_splat_value_struct.field_one, "collapse");
+    assert_eq!(test_splat_value_struct.field_two.1, "collapse");
+
+    let mut test_packed_splat_value_struct = TestPackedSplatValueStruct::splat(100);
+    assert_eq!(test_packed_splat_value_struct.splat_value(), Some(100));
+    test_packed_splat_value_struct.field_two = [100, 101];
+    assert_eq!(test_packed_splat_value_struct.splat_value(), None);
+    assert_eq!({ test_packed_splat_value_struct.field_one }, 100);
+}
+
+#[derive(Splat)]
+#[splat(extra(eq_splat))]
+struct TestEqSplatStruct {
+    field_one: f32,
+    field_two: [f32; 2],
+    #[splat(skip_with = "1.0")]
+    scale: f32,
+}
+
+#[test]
+fn eq_splat_struct_fields() {
+    let mut test_eq_splat_struct = TestEqSplatStruct::splat(0.0);
+    assert!(test_eq_splat_struct.eq_splat(&0.0));
+    assert!(!test_eq_splat_struct.eq_splat(&102.0));
+    assert_eq!(test_eq_splat_struct.scale, 1.0);
+
+    test_eq_splat_struct.field_two[0] = 103.0;
+    assert!(!test_eq_splat_struct.eq_splat(&0.0));
+    assert_eq!(test_eq_splat_struct.field_one, 0.0);
+}
+
+#[derive(Splat)]
+#[splat(extra(scale_all, add_all))]
+struct TestAssignAllStruct {
+    field_one: i32,
+    field_two: [i32; 2],
+}
+
+#[derive(Splat)]
+#[splat(extra(scale_all))]
+#[repr(C, packed)]
+struct TestPackedAssignAllStruct {
+    field_one: f32,
+    field_two: (f32, f32),
+}
+
+#[test]
+fn assign_all_struct_fields() {
+    let mut test_assign_all_struct = TestAssignAllStruct::splat(52);
+    test_assign_all_struct.scale_all(2);
+    test_assign_all_struct.add_all(-104);
+    test_assign_all_struct.field_two[1] += 105;
+    test_assign_all_struct.add_all(1);
+    assert_eq!(test_assign_all_struct.field_one, 1);
+    assert_eq!(test_assign_all_struct.field_two, [1, 106]);
+
+    let mut test_packed_assign_all_struct = TestPackedAssignAllStruct::splat(53.5);
+    test_packed_assign_all_struct.scale_all(2.0);
+    assert_eq!({ test_packed_assign_all_struct.field_one }, 107.0);
+    assert_eq!({ test_packed_assign_all_struct.field_two }, (107.0, 107.0));
+}
+
+#[derive(Splat)]
+#[splat(extra(to_ref_array, to_mut_array))]
+struct TestToArrayStruct {
+    field_one: String,
+    field_two: [String; 2],
+    #[splat(skip)]
+    id: u64,
+    field_three: ([String; 1], String),
+}
+
+#[derive(Splat)]
+#[splat(extra(to_ref_array))]
+struct TestGenericToArrayStruct<const N: usize> {
+    field_one: [u8; N],
+}
+
+#[test]
+fn to_array_struct_fields() {
+    let mut test_to_array_struct = TestToArrayStruct::splat(String::from("array"));
+    for (index, field) in test_to_array_struct.to_mut_array().into_iter().enumerate() {
+        field.push_str(&index.to_string());
+    }
+    assert_eq!(
+        test_to_array_struct.to_ref_array(),
+        ["array0", "array1", "array2", "array3", "array4"]
+            .map(String::from)
+            .each_ref()
+    );
+    assert_eq!(test_to_array_struct.id, 0);
+    assert_eq!(test_to_array_struct.field_one, "array0");
+    assert_eq!(test_to_array_struct.field_three.1, "array4");
+
+    let test_generic_to_array_struct = TestGenericToArrayStruct::<3>::splat(108);
+    assert_eq!(test_generic_to_array_struct.to_ref_array(), [&108; 3]);
+    assert_eq!(test_generic_to_array_struct.field_one, [108; 3]);
+}
+
+#[derive(Splat)]
+#[splat(extra(splat_then))]
+struct TestSplatThenStruct {
+    field_one: u8,
+    field_two: u8,
+    field_three: u8,
+}
+
+#[test]
+fn splat_then_struct_fields() {
+    let test_splat_then_struct = TestSplatThenStruct::splat_then(109, |test_splat_then_struct| {
+        test_splat_then_struct.field_two = 110;
+    });
+    assert_eq!(test_splat_then_struct.field_one, 109);
+    assert_eq!(test_splat_then_struct.field_two, 110);
+    assert_eq!(test_splat_then_struct.field_three, 109);
+}
+
+#[derive(Splat)]
+#[splat(const_value(ZEROED = "0", FULL = "u8::MAX"))]
+struct TestConstValueStruct {
+    field_one: u8,
+    field_two: [u8; 2],
+    #[splat(skip_with = "111")]
+    id: u8,
+}
+
+const TEST_CONST_VALUE_STRUCT: TestConstValueStruct = TestConstValueStruct::FULL;
+
+#[test]
+fn const_value_struct_fields() {
+    let test_const_value_struct = TestConstValueStruct::ZEROED;
+    assert_eq!(test_const_value_struct.field_one, 0);
+    assert_eq!(test_const_value_struct.field_two, [0, 0]);
+    assert_eq!(test_const_value_struct.id, 111);
+
+    assert_eq!(TEST_CONST_VALUE_STRUCT.field_one, u8::MAX);
+    assert_eq!(TEST_CONST_VALUE_STRUCT.field_two, [u8::MAX; 2]);
+}
+
+#[derive(Splat)]
+#[splat(impl_default)]
+struct TestImplDefaultStruct {
+    field_one: String,
+    field_two: [String; 2],
+}
+
+#[derive(Splat)]
+#[splat(impl_default = "112")]
+struct TestImplDefaultValueStruct {
+    field_one: u32,
+    field_two: (u32, u32),
+}
+
+#[test]
+fn impl_default_struct_fields() {
+    let test_impl_default_struct = TestImplDefaultStruct::default();
+    assert_eq!(test_impl_default_struct.field_one, "");
+    assert_eq!(test_impl_default_struct.field_two, ["", ""]);
+
+    let test_impl_default_value_struct = TestImplDefaultValueStruct::default();
+    assert_eq!(test_impl_default_value_struct.field_one, 112);
+    assert_eq!(test_impl_default_value_struct.field_two, (112, 112));
+}
+
+#[derive(Splat)]
+#[splat(impl_from)]
+struct TestImplFromStruct<T> {
+    field_one: T,
+    field_two: [T; 2],
+}
+
+fn splat_into<S: From<u16>>(value: u16) -> S {
+    value.into()
+}
+
+#[test]
+fn impl_from_struct_fields() {
+    let test_impl_from_struct: TestImplFromStruct<String> = String::from("from").into();
+    assert_eq!(test_impl_from_struct.field_one, "from");
+    assert_eq!(test_impl_from_struct.field_two, ["from", "from"]);
+
+    let test_impl_from_struct = splat_into::<TestImplFromStruct<u16>>(113);
+    assert_eq!(test_impl_from_struct.field_one, 113);
+    assert_eq!(test_impl_from_struct.field_two, [113, 113]);
+}